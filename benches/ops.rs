@@ -0,0 +1,109 @@
+// plain timing harness over the public API, run with `cargo bench`
+use std::hint::black_box;
+use std::time::Instant;
+
+use zook::ec::CurvePoint;
+use zook::ff::{FieldElement, SplitFieldElement};
+
+const ITERATIONS: u32 = 100_000;
+
+fn bench(group: &str, name: &str, mut f: impl FnMut(u32)) {
+    let start = Instant::now();
+    for i in 0..ITERATIONS {
+        f(black_box(i));
+    }
+    let per_iter = start.elapsed().as_nanos() / u128::from(ITERATIONS);
+    println!("{group}/{name}: {per_iter} ns/iter");
+}
+
+fn field<const P: u32>(group: &str) {
+    let a = FieldElement::<P>::new(P / 3 + 1);
+
+    bench(group, "mul", |i| {
+        black_box(a * FieldElement::new(i));
+    });
+    bench(group, "sub", |i| {
+        black_box(a - FieldElement::new(i));
+    });
+    bench(group, "neg", |i| {
+        black_box(-FieldElement::<P>::new(i));
+    });
+    bench(group, "pow", |i| {
+        black_box(a.pow(i));
+    });
+    bench(group, "inverse", |i| {
+        black_box(FieldElement::<P>::new(i).inverse());
+    });
+}
+
+// direct reduction against componentwise residues for a modulus with many small factors
+fn split() {
+    const P: u32 = 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23;
+    let a = FieldElement::<P>::new(P / 3 + 1);
+    let b = FieldElement::<P>::new(P / 7 + 5);
+    let (x, y) = (SplitFieldElement::from(a), SplitFieldElement::from(b));
+
+    bench("split_111546435", "direct_mul", |_| {
+        black_box(black_box(a) * b);
+    });
+    bench("split_111546435", "split_mul", |_| {
+        black_box(black_box(x.clone()) * y.clone());
+    });
+    bench("split_111546435", "split_round_trip", |_| {
+        black_box(FieldElement::from(SplitFieldElement::from(black_box(a))));
+    });
+}
+
+fn curve() {
+    // y^2 = x^3 + 2x + 2 over F_17
+    let g = CurvePoint::<2, 2, 17>::Point {
+        x: FieldElement::new(5),
+        y: FieldElement::new(1),
+    };
+    let h = g.double();
+
+    bench("curve_17", "add", |_| {
+        black_box(black_box(g) + h);
+    });
+    bench("curve_17", "double", |_| {
+        black_box(black_box(g).double());
+    });
+    bench("curve_17", "mul_scalar", |i| {
+        black_box(g.mul_scalar(i));
+    });
+    bench("curve_17", "mul_scalar_auto", |i| {
+        black_box(g.mul_scalar_auto(i));
+    });
+}
+
+// backs mul_scalar_auto: compares plain double-and-add against each window size
+fn curve_windows() {
+    let g = CurvePoint::<2, 2, 17>::Point {
+        x: FieldElement::new(5),
+        y: FieldElement::new(1),
+    };
+
+    for bits in [4, 8, 12, 16, 24, 32] {
+        let mask = u32::MAX >> (32 - bits);
+        bench("curve_17", &format!("mul_scalar/{bits}_bits"), |i| {
+            let k = i.wrapping_mul(2654435761) & mask | 1 << (bits - 1);
+            black_box(g.mul_scalar(k));
+        });
+        for window in 1..=4 {
+            let name = format!("mul_scalar_windowed/{bits}_bits/w{window}");
+            bench("curve_17", &name, |i| {
+                let k = i.wrapping_mul(2654435761) & mask | 1 << (bits - 1);
+                black_box(g.mul_scalar_windowed(k, window));
+            });
+        }
+    }
+}
+
+fn main() {
+    field::<97>("field_97");
+    field::<65537>("field_65537");
+    field::<4294967291>("field_4294967291");
+    split();
+    curve();
+    curve_windows();
+}
@@ -0,0 +1,174 @@
+use std::num::NonZeroU32;
+
+use super::CurvePoint;
+use crate::ff::{modulus_add, modulus_div, modulus_mul, modulus_sub};
+
+// y^2 = x^3 + ax + b over F_p with parameters chosen at runtime, mirrors CurvePoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynCurve {
+    pub a: u32,
+    pub b: u32,
+    pub p: u32,
+}
+
+// coordinates are residues mod the curve's p, double and add reduce them before comparing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DynPoint {
+    Zero,
+    Point { x: u32, y: u32 },
+}
+
+impl DynCurve {
+    fn modulus(&self) -> NonZeroU32 {
+        NonZeroU32::new(self.p).expect("DynCurve can't have 0 as a modulo")
+    }
+
+    fn div(&self, a: u32, b: u32) -> u32 {
+        let b = NonZeroU32::new(b).expect("division by 0");
+        modulus_div(a, b, self.modulus()).expect("gcd(a,n) != 1")
+    }
+
+    fn reduce(&self, p: DynPoint) -> DynPoint {
+        let n = self.modulus().get();
+        match p {
+            DynPoint::Zero => DynPoint::Zero,
+            DynPoint::Point { x, y } => DynPoint::Point { x: x % n, y: y % n },
+        }
+    }
+
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        let n = self.modulus();
+        if x >= n.get() || y >= n.get() {
+            return false;
+        }
+
+        let x3 = modulus_mul(modulus_mul(x, x, n), x, n);
+        let rhs = modulus_add(modulus_add(x3, modulus_mul(self.a, x, n), n), self.b, n);
+        modulus_mul(y, y, n) == rhs
+    }
+
+    pub fn double(&self, p: DynPoint) -> DynPoint {
+        let n = self.modulus();
+        match self.reduce(p) {
+            DynPoint::Zero => DynPoint::Zero,
+            // vertical tangent, y == -y as in CurvePoint::double
+            DynPoint::Point { y, .. } if y == modulus_sub(0, y, n) => DynPoint::Zero,
+            DynPoint::Point { x, y } => {
+                let numerator = modulus_add(modulus_mul(3, modulus_mul(x, x, n), n), self.a, n);
+                let s = self.div(numerator, modulus_add(y, y, n));
+                let x3 = modulus_sub(modulus_sub(modulus_mul(s, s, n), x, n), x, n);
+                let y3 = modulus_sub(modulus_mul(s, modulus_sub(x, x3, n), n), y, n);
+
+                DynPoint::Point { x: x3, y: y3 }
+            }
+        }
+    }
+
+    pub fn add(&self, p1: DynPoint, p2: DynPoint) -> DynPoint {
+        let n = self.modulus();
+        let (p1, p2) = (self.reduce(p1), self.reduce(p2));
+        match (p1, p2) {
+            (DynPoint::Zero, _) => p2,
+            (_, DynPoint::Zero) => p1,
+            (DynPoint::Point { x: x1, y: y1 }, DynPoint::Point { x: x2, y: y2 }) => {
+                if x1 == x2 {
+                    // p2 is p1 or -p1, checked in the same order as CurvePoint
+                    if y1 == modulus_sub(0, y2, n) {
+                        DynPoint::Zero
+                    } else {
+                        self.double(p1)
+                    }
+                } else {
+                    let s = self.div(modulus_sub(y1, y2, n), modulus_sub(x1, x2, n));
+                    let x = modulus_sub(modulus_sub(modulus_mul(s, s, n), x1, n), x2, n);
+                    let y = modulus_sub(modulus_mul(s, modulus_sub(x1, x, n), n), y1, n);
+
+                    DynPoint::Point { x, y }
+                }
+            }
+        }
+    }
+}
+
+impl<const A: u32, const B: u32, const P: u32> From<CurvePoint<A, B, P>> for DynPoint {
+    fn from(p: CurvePoint<A, B, P>) -> Self {
+        match p {
+            CurvePoint::Zero => DynPoint::Zero,
+            CurvePoint::Point { x, y } => DynPoint::Point {
+                x: x.val(),
+                y: y.val(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ff::FieldElement;
+
+    const CURVE: DynCurve = DynCurve { a: 2, b: 2, p: 17 };
+
+    fn group() -> Vec<CurvePoint<2, 2, 17>> {
+        let g = CurvePoint::Point {
+            x: FieldElement::new(5),
+            y: FieldElement::new(1),
+        };
+        (0..19).map(|k| g.mul_scalar(k)).collect()
+    }
+
+    #[test]
+    fn it_contains_the_const_generic_points() {
+        for p in group() {
+            if let DynPoint::Point { x, y } = DynPoint::from(p) {
+                assert!(CURVE.contains(x, y));
+            }
+        }
+        assert!(!CURVE.contains(5, 2));
+        assert!(!CURVE.contains(5 + 17, 1));
+    }
+
+    #[test]
+    fn it_agrees_with_const_generic_addition() {
+        let group = group();
+        for &p in &group {
+            for &q in &group {
+                assert_eq!(CURVE.add(p.into(), q.into()), DynPoint::from(p + q));
+            }
+            assert_eq!(CURVE.double(p.into()), DynPoint::from(p.double()));
+        }
+
+        // over F_2 every point is its own negation
+        let curve = DynCurve { a: 0, b: 1, p: 2 };
+        let mut group = vec![CurvePoint::<0, 1, 2>::Zero];
+        for x in 0..2 {
+            group.extend(CurvePoint::points_with_x(FieldElement::new(x)));
+        }
+        for &p in &group {
+            for &q in &group {
+                assert_eq!(curve.add(p.into(), q.into()), DynPoint::from(p + q));
+            }
+            assert_eq!(curve.double(p.into()), DynPoint::from(p.double()));
+        }
+    }
+
+    #[test]
+    fn it_reduces_non_canonical_coordinates() {
+        let g = DynPoint::Point { x: 5, y: 1 };
+        let shifted = DynPoint::Point {
+            x: 5 + 17,
+            y: 1 + 34,
+        };
+        assert_eq!(CURVE.add(g, shifted), CURVE.double(g));
+        assert_eq!(CURVE.double(shifted), CURVE.double(g));
+        assert_eq!(
+            CURVE.add(shifted, DynPoint::Point { x: 5, y: 16 }),
+            DynPoint::Zero
+        );
+        // y = p is the 2-torsion case y = 0 in disguise
+        assert_eq!(
+            DynCurve { a: 1, b: 0, p: 7 }.double(DynPoint::Point { x: 0, y: 7 }),
+            DynPoint::Zero
+        );
+    }
+}
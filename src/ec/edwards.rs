@@ -0,0 +1,173 @@
+use std::ops::{Add, Neg, Sub};
+
+use crate::error::ZookError;
+use crate::ff::FieldElement;
+
+// a * x^2 + y^2 = 1 + d * x^2 * y^2, the addition law has no exceptional cases when a is a
+// square and d is not
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EdwardsPoint<const A: u32, const D: u32, const P: u32> {
+    x: FieldElement<P>,
+    y: FieldElement<P>,
+}
+
+impl<const A: u32, const D: u32, const P: u32> EdwardsPoint<A, D, P> {
+    // Err(NotOnCurve) if (x, y) is not on the curve
+    pub fn new(x: FieldElement<P>, y: FieldElement<P>) -> Result<EdwardsPoint<A, D, P>, ZookError> {
+        let p = EdwardsPoint { x, y };
+        p.is_on_curve().then_some(p).ok_or(ZookError::NotOnCurve)
+    }
+
+    // the neutral element (0, 1)
+    pub fn identity() -> EdwardsPoint<A, D, P> {
+        EdwardsPoint {
+            x: FieldElement::zero(),
+            y: FieldElement::one(),
+        }
+    }
+
+    pub fn x(&self) -> FieldElement<P> {
+        self.x
+    }
+
+    pub fn y(&self) -> FieldElement<P> {
+        self.y
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let (x2, y2) = (self.x * self.x, self.y * self.y);
+        FieldElement::new(A) * x2 + y2 == FieldElement::one() + FieldElement::new(D) * x2 * y2
+    }
+
+    // dedicated doubling, the addition law with a * x^2 + y^2 substituted for 1 + d * x^2 * y^2
+    pub fn double(self) -> Self {
+        let (x, y) = (self.x, self.y);
+        let (ax2, y2) = (FieldElement::new(A) * x * x, y * y);
+        let two = FieldElement::new(2);
+
+        EdwardsPoint {
+            x: two * x * y / (ax2 + y2),
+            y: (y2 - ax2) / (two - ax2 - y2),
+        }
+    }
+
+    // double-and-add
+    pub fn mul_scalar(self, k: u32) -> Self {
+        let mut acm = Self::identity();
+        let mut cur = self;
+        let bits = u32::BITS - k.leading_zeros();
+
+        for i in 0..bits {
+            if (k >> i) & 1 == 1 {
+                acm = acm + cur;
+            }
+            cur = cur.double();
+        }
+
+        acm
+    }
+}
+
+impl<const A: u32, const D: u32, const P: u32> Add for EdwardsPoint<A, D, P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (x1, y1, x2, y2) = (self.x, self.y, rhs.x, rhs.y);
+        let t = FieldElement::new(D) * x1 * x2 * y1 * y2;
+
+        let x = (x1 * y2 + y1 * x2) / (FieldElement::one() + t);
+        let y = (y1 * y2 - FieldElement::new(A) * x1 * x2) / (FieldElement::one() - t);
+
+        EdwardsPoint { x, y }
+    }
+}
+
+impl<const A: u32, const D: u32, const P: u32> Neg for EdwardsPoint<A, D, P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        EdwardsPoint {
+            x: -self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<const A: u32, const D: u32, const P: u32> Sub for EdwardsPoint<A, D, P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // x^2 + y^2 = 1 + 2x^2y^2 over F_13, 1 is a square and 2 is not
+    type Point = EdwardsPoint<1, 2, 13>;
+
+    fn all_points() -> Vec<Point> {
+        (0..13)
+            .flat_map(|x| (0..13).map(move |y| (x, y)))
+            .filter_map(|(x, y)| Point::new(FieldElement::new(x), FieldElement::new(y)).ok())
+            .collect()
+    }
+
+    #[test]
+    fn it_rejects_off_curve_points() {
+        assert_eq!(
+            Point::new(FieldElement::new(1), FieldElement::new(1)),
+            Err(ZookError::NotOnCurve)
+        );
+        assert!(Point::new(FieldElement::new(0), FieldElement::new(1)).is_ok());
+    }
+
+    #[test]
+    fn identity_is_neutral() {
+        for p in all_points() {
+            assert_eq!(p + Point::identity(), p);
+            assert_eq!(Point::identity() + p, p);
+        }
+    }
+
+    #[test]
+    fn addition_is_closed_commutative_and_associative() {
+        let points = all_points();
+        for &p in &points {
+            for &q in &points {
+                assert!((p + q).is_on_curve());
+                assert_eq!(p + q, q + p);
+                for &r in &points {
+                    assert_eq!((p + q) + r, p + (q + r));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn negation_is_inverse() {
+        for p in all_points() {
+            assert_eq!(p - p, Point::identity());
+        }
+    }
+
+    #[test]
+    fn doubling_equals_addition() {
+        for p in all_points() {
+            assert!(p.double().is_on_curve());
+            assert_eq!(p.double(), p + p);
+            assert_eq!(p.mul_scalar(2), p + p);
+            assert_eq!(p.mul_scalar(3), p + p + p);
+        }
+    }
+
+    #[test]
+    fn group_order_annihilates() {
+        let order = all_points().len() as u32;
+        for p in all_points() {
+            assert_eq!(p.mul_scalar(order), Point::identity());
+        }
+    }
+}
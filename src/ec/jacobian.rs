@@ -0,0 +1,188 @@
+use super::CurvePoint;
+use crate::ff::{self, FieldElement};
+
+// (X, Y, Z) represents the affine point (X / Z^2, Y / Z^3), any Z == 0 is the identity
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct JacobianPoint<const A: u32, const B: u32, const P: u32> {
+    x: FieldElement<P>,
+    y: FieldElement<P>,
+    z: FieldElement<P>,
+}
+
+impl<const A: u32, const B: u32, const P: u32> JacobianPoint<A, B, P> {
+    pub(crate) fn identity() -> Self {
+        JacobianPoint {
+            x: FieldElement::one(),
+            y: FieldElement::one(),
+            z: FieldElement::zero(),
+        }
+    }
+
+    pub(crate) fn from_affine(p: CurvePoint<A, B, P>) -> Self {
+        match p {
+            CurvePoint::Zero => Self::identity(),
+            CurvePoint::Point { x, y } => JacobianPoint {
+                x,
+                y,
+                z: FieldElement::one(),
+            },
+        }
+    }
+
+    pub(crate) fn to_affine(self) -> CurvePoint<A, B, P> {
+        match self.z.inverse() {
+            None => CurvePoint::Zero,
+            Some(z_inv) => {
+                let z_inv2 = z_inv * z_inv;
+                CurvePoint::Point {
+                    x: self.x * z_inv2,
+                    y: self.y * z_inv2 * z_inv,
+                }
+            }
+        }
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.z.val() == 0
+    }
+
+    pub(crate) fn double(self) -> Self {
+        if self.is_identity() || self.y.val() == 0 {
+            return Self::identity();
+        }
+        let yy = self.y * self.y;
+        let zz = self.z * self.z;
+        let s = FieldElement::new(4) * self.x * yy;
+        let m = FieldElement::new(3) * self.x * self.x + FieldElement::new(A) * zz * zz;
+        let x = m * m - s - s;
+        JacobianPoint {
+            x,
+            y: m * (s - x) - FieldElement::new(8) * yy * yy,
+            z: FieldElement::new(2) * self.y * self.z,
+        }
+    }
+
+    pub(crate) fn add(self, rhs: Self) -> Self {
+        if self.is_identity() {
+            return rhs;
+        }
+        if rhs.is_identity() {
+            return self;
+        }
+
+        let z1z1 = self.z * self.z;
+        let z2z2 = rhs.z * rhs.z;
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * z2z2 * rhs.z;
+        let s2 = rhs.y * z1z1 * self.z;
+
+        if u1 == u2 {
+            // same x, so rhs is self or -self
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Self::identity()
+            };
+        }
+
+        let h = u2 - u1;
+        let r = s2 - s1;
+        let hh = h * h;
+        let hhh = hh * h;
+        let x = r * r - hhh - FieldElement::new(2) * u1 * hh;
+        JacobianPoint {
+            x,
+            y: r * (u1 * hh - x) - s1 * hhh,
+            z: h * self.z * rhs.z,
+        }
+    }
+
+    // the same point with coordinates (l^2 X, l^3 Y, l Z)
+    #[cfg(test)]
+    pub(crate) fn rescale(self, l: FieldElement<P>) -> Self {
+        JacobianPoint {
+            x: self.x * l * l,
+            y: self.y * l * l * l,
+            z: self.z * l,
+        }
+    }
+}
+
+// converts with a single field inversion
+pub(crate) fn batch_to_affine<const A: u32, const B: u32, const P: u32>(
+    points: &[JacobianPoint<A, B, P>],
+) -> Vec<CurvePoint<A, B, P>> {
+    let zs: Vec<_> = points
+        .iter()
+        .filter(|p| !p.is_identity())
+        .map(|p| p.z)
+        .collect();
+    let mut z_invs = ff::batch_inverse(&zs).into_iter();
+
+    points
+        .iter()
+        .map(|p| {
+            if p.is_identity() {
+                return CurvePoint::Zero;
+            }
+            let z_inv = z_invs.next().unwrap();
+            let z_inv2 = z_inv * z_inv;
+            CurvePoint::Point {
+                x: p.x * z_inv2,
+                y: p.y * z_inv2 * z_inv,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point = CurvePoint<2, 2, 17>;
+
+    fn elements() -> Vec<Point> {
+        let g = Point::Point {
+            x: FieldElement::new(5),
+            y: FieldElement::new(1),
+        };
+        g.subgroup_elements().collect()
+    }
+
+    #[test]
+    fn arithmetic_matches_affine() {
+        for p in elements() {
+            // rescaling exercises z != 1
+            let jp = JacobianPoint::from_affine(p).rescale(FieldElement::new(3));
+            assert_eq!(jp.double().to_affine(), p.double());
+            for q in elements() {
+                let jq = JacobianPoint::from_affine(q).rescale(FieldElement::new(5));
+                assert_eq!(jp.add(jq).to_affine(), p + q);
+            }
+        }
+    }
+
+    #[test]
+    fn doubling_2_torsion_is_the_identity() {
+        let t = CurvePoint::<1, 0, 7>::Point {
+            x: FieldElement::new(0),
+            y: FieldElement::new(0),
+        };
+        assert!(JacobianPoint::from_affine(t).double().is_identity());
+    }
+
+    #[test]
+    fn batch_to_affine_matches_to_affine() {
+        let points: Vec<_> = elements()
+            .into_iter()
+            .enumerate()
+            .map(|(i, p)| {
+                JacobianPoint::from_affine(p).rescale(FieldElement::new(i as u32 % 16 + 1))
+            })
+            .collect();
+        let expected: Vec<_> = points.iter().map(|p| p.to_affine()).collect();
+        assert_eq!(batch_to_affine(&points), expected);
+        assert_eq!(batch_to_affine::<2, 2, 17>(&[]), vec![]);
+    }
+}
@@ -1,9 +1,28 @@
-use std::ops::{Add, Sub};
+use std::collections::HashMap;
+use std::ops::{Add, Neg, Sub};
 
-use crate::ff::FieldElement;
+use crate::error::ZookError;
+use crate::ff::{self, FieldElement};
 
-#[derive(Copy, Clone)]
-enum CurvePoint<const A: u32, const B: u32, const P: u32> {
+mod dynamic;
+mod edwards;
+mod jacobian;
+mod msm;
+#[cfg(test)]
+mod test_support;
+
+pub use dynamic::{DynCurve, DynPoint};
+pub use edwards::EdwardsPoint;
+pub use msm::msm;
+
+pub trait Group: Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self> + Copy + Eq {
+    fn identity() -> Self;
+    fn mul_scalar(self, k: u32) -> Self;
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CurvePoint<const A: u32, const B: u32, const P: u32> {
+    #[default]
     Zero,
     Point {
         x: FieldElement<P>,
@@ -11,6 +30,314 @@ enum CurvePoint<const A: u32, const B: u32, const P: u32> {
     },
 }
 
+impl<const A: u32, const B: u32, const P: u32> CurvePoint<A, B, P> {
+    pub const COORD_BYTES: usize = FieldElement::<P>::COORD_BYTES;
+
+    pub fn new(x: FieldElement<P>, y: FieldElement<P>) -> Result<Self, ZookError> {
+        let p = CurvePoint::Point { x, y };
+        p.is_on_curve().then_some(p).ok_or(ZookError::NotOnCurve)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        matches!(self, CurvePoint::Zero)
+    }
+
+    pub fn is_point(&self) -> bool {
+        !self.is_zero()
+    }
+
+    // compares the affine forms, which for CurvePoint coincides with ==
+    pub fn eq_point(&self, other: &Self) -> bool {
+        jacobian::JacobianPoint::from_affine(*self).to_affine()
+            == jacobian::JacobianPoint::from_affine(*other).to_affine()
+    }
+
+    // x^3 + Ax + B
+    fn curve_rhs(x: FieldElement<P>) -> FieldElement<P> {
+        x * x * x + FieldElement::new(A) * x + FieldElement::new(B)
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        match *self {
+            CurvePoint::Zero => true,
+            CurvePoint::Point { x, y } => y * y == Self::curve_rhs(x),
+        }
+    }
+
+    // Zero is the single byte 0, a point is 1 followed by x and y
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match *self {
+            CurvePoint::Zero => vec![0],
+            CurvePoint::Point { x, y } => {
+                let mut bytes = vec![1];
+                bytes.extend(x.to_bytes());
+                bytes.extend(y.to_bytes());
+                bytes
+            }
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ZookError> {
+        match bytes.split_first() {
+            Some((0, [])) => Ok(CurvePoint::Zero),
+            Some((1, coords)) if coords.len() == 2 * Self::COORD_BYTES => {
+                let (x, y) = coords.split_at(Self::COORD_BYTES);
+                Self::new(FieldElement::from_bytes(x)?, FieldElement::from_bytes(y)?)
+            }
+            Some((0 | 1, _)) | None => Err(ZookError::LengthMismatch),
+            Some(_) => Err(ZookError::InvalidEncoding),
+        }
+    }
+
+    // debug-only self check, e.g. after deserialization or hash-to-curve
+    pub fn assert_valid(&self, order: u32) {
+        debug_assert!(self.is_on_curve(), "point is not on the curve");
+        debug_assert!(
+            self.mul_scalar(order).is_zero(),
+            "point is not in the subgroup of the given order"
+        );
+    }
+
+    // exact check of every point, a randomized batch check would need a rand dependency
+    pub fn all_on_curve(points: &[Self]) -> bool {
+        points.iter().all(Self::is_on_curve)
+    }
+
+    // number of points including Zero, found by counting so only practical for small P
+    pub fn curve_order() -> u32 {
+        // points_with_x already collapses y == -y, which over F_2 holds for every y
+        (0..P)
+            .map(|x| Self::points_with_x(FieldElement::new(x)).len() as u32)
+            .sum::<u32>()
+            + 1
+    }
+
+    // the points with the given x coordinate, none, one (y = 0), or two (y and -y)
+    pub fn points_with_x(x: FieldElement<P>) -> Vec<CurvePoint<A, B, P>> {
+        match Self::curve_rhs(x).sqrt() {
+            None => vec![],
+            Some(y) if y == -y => vec![CurvePoint::Point { x, y }],
+            Some(y) => vec![CurvePoint::Point { x, y }, CurvePoint::Point { x, y: -y }],
+        }
+    }
+
+    // BIP340 lift_x: the point with x and even y, None when x isn't on the curve
+    pub fn lift_x(x: FieldElement<P>) -> Option<Self> {
+        let y = Self::curve_rhs(x).sqrt()?;
+        let y = if y.val().is_multiple_of(2) { y } else { -y };
+        Some(CurvePoint::Point { x, y })
+    }
+
+    // chord addition without the exceptional cases, both inputs must be points with x1 != x2
+    pub fn add_distinct(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (CurvePoint::Point { x: x1, y: y1 }, CurvePoint::Point { x: x2, y: y2 }) => {
+                debug_assert!(x1 != x2, "add_distinct requires distinct x coordinates");
+                let s = (y1 - y2) / (x1 - x2);
+                let x = s * s - x1 - x2;
+                let y = s * (x1 - x) - y1;
+
+                CurvePoint::Point { x, y }
+            }
+            _ => panic!("add_distinct requires two points other than Zero"),
+        }
+    }
+
+    pub fn double(self) -> Self {
+        match self {
+            CurvePoint::Zero => CurvePoint::Zero,
+            CurvePoint::Point { x, y } => {
+                // the tangent is vertical when y == -y, i.e. y = 0 or any point over F_2, so
+                // the slope's 2y denominator is never 0 below and p + p agrees with double
+                if y == -y {
+                    CurvePoint::Zero
+                } else {
+                    let s = (FieldElement::new(3) * x * x + FieldElement::new(A)) / (y + y);
+                    let x3 = s * s - x - x;
+                    let y3 = s * (x - x3) - y;
+
+                    CurvePoint::Point { x: x3, y: y3 }
+                }
+            }
+        }
+    }
+
+    // double-and-add
+    pub fn mul_scalar(self, k: u32) -> Self {
+        let mut acm = CurvePoint::Zero;
+        let mut cur = self;
+        let bits = u32::BITS - k.leading_zeros();
+
+        for i in 0..bits {
+            if (k >> i) & 1 == 1 {
+                acm = acm + cur;
+            }
+            cur = cur.double();
+        }
+
+        acm
+    }
+
+    // naive double() + self, there is no fused tripling formula
+    pub fn triple(self) -> Self {
+        self.double() + self
+    }
+
+    // sliding window multiplication, precomputes 1, 3, ..., (2^window - 1) times self
+    pub fn mul_scalar_windowed(self, k: u32, window: usize) -> Self {
+        if !(1..=8).contains(&window) {
+            panic!("window size must be between 1 and 8")
+        }
+
+        // fixed size so the table lives on the stack, only the first 2^(window - 1) are used
+        let double = self.double();
+        let mut odd_multiples = [CurvePoint::Zero; 128];
+        odd_multiples[0] = self;
+        for i in 1..(1 << (window - 1)) {
+            odd_multiples[i] = odd_multiples[i - 1] + double;
+        }
+
+        let window = window as u32;
+        let mut acm = CurvePoint::Zero;
+        let mut i = u32::BITS - k.leading_zeros();
+
+        while i > 0 {
+            if (k >> (i - 1)) & 1 == 0 {
+                acm = acm.double();
+                i -= 1;
+            } else {
+                // take the longest run of at most `window` bits ending in a set bit
+                let mut j = i.saturating_sub(window);
+                while (k >> j) & 1 == 0 {
+                    j += 1;
+                }
+
+                for _ in j..i {
+                    acm = acm.double();
+                }
+
+                let digit = (k >> j) & ((1 << (i - j)) - 1);
+                acm = acm + odd_multiples[(digit >> 1) as usize];
+                i = j;
+            }
+        }
+
+        acm
+    }
+
+    // the curve_17 benchmarks have plain double-and-add ahead of every window size for
+    // all u32 scalars (e.g. 2888 vs 2971 ns at 32 bits for w2), since windowing only
+    // saves additions and the doublings dominate, so this falls back to mul_scalar
+    pub fn mul_scalar_auto(self, k: u32) -> Self {
+        self.mul_scalar(k)
+    }
+
+    // baby-step giant-step discrete log of self to the base generator, takes O(sqrt(n)) time
+    // and memory so it is only feasible for small subgroups
+    pub fn to_scalar(self, generator: Self, subgroup_order: u32) -> Option<u32> {
+        if subgroup_order == 0 {
+            return None;
+        }
+
+        let mut m = subgroup_order.isqrt();
+        if m * m < subgroup_order {
+            m += 1;
+        }
+
+        let mut baby_steps = HashMap::new();
+        let mut cur = CurvePoint::Zero;
+        for j in 0..m {
+            baby_steps.entry(cur).or_insert(j);
+            cur = cur + generator;
+        }
+
+        let giant_step = -generator.mul_scalar(m);
+        let mut gamma = self;
+        for i in 0..m {
+            if let Some(&j) = baby_steps.get(&gamma) {
+                let k = u64::from(i) * u64::from(m) + u64::from(j);
+                return Some((k % u64::from(subgroup_order)) as u32);
+            }
+            gamma = gamma + giant_step;
+        }
+
+        None
+    }
+
+    // O, self, 2 * self, ... up to the last multiple before wrapping back to O
+    pub fn subgroup_elements(self) -> impl Iterator<Item = Self> {
+        std::iter::successors(Some(CurvePoint::Zero), move |&p| {
+            let next = p + self;
+            (next != CurvePoint::Zero).then_some(next)
+        })
+    }
+
+    // start + sum(points), added pairwise in a tree so each level shares one batched inversion
+    pub fn add_many(start: Self, points: &[Self]) -> Self {
+        let mut layer: Vec<Self> = std::iter::once(start)
+            .chain(points.iter().copied())
+            .collect();
+
+        while layer.len() > 1 {
+            let pairs: Vec<_> = layer.chunks(2).collect();
+
+            // chord denominators of pairs with distinct x coordinates, the rest go through add
+            let denominators: Vec<_> = pairs
+                .iter()
+                .filter_map(|pair| match pair {
+                    [CurvePoint::Point { x: x1, .. }, CurvePoint::Point { x: x2, .. }]
+                        if x1 != x2 =>
+                    {
+                        Some(*x2 - *x1)
+                    }
+                    _ => None,
+                })
+                .collect();
+            let mut inverses = ff::batch_inverse(&denominators).into_iter();
+
+            layer = pairs
+                .into_iter()
+                .map(|pair| match *pair {
+                    [CurvePoint::Point { x: x1, y: y1 }, CurvePoint::Point { x: x2, y: y2 }]
+                        if x1 != x2 =>
+                    {
+                        let s = (y2 - y1) * inverses.next().unwrap();
+                        let x = s * s - x1 - x2;
+                        let y = s * (x1 - x) - y1;
+
+                        CurvePoint::Point { x, y }
+                    }
+                    [a, b] => a + b,
+                    [a] => a,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        layer[0]
+    }
+
+    // double-and-add over a little-endian bit decomposition of the scalar
+    pub fn mul_scalar_bits(self, bits: &[bool]) -> Self {
+        let mut acm = CurvePoint::Zero;
+        let mut cur = self;
+
+        for &bit in bits {
+            if bit {
+                acm = acm + cur;
+            }
+            cur = cur.double();
+        }
+
+        acm
+    }
+
+    // N is the order of the subgroup the scalar acts on
+    pub fn mul_scalar_fe<const N: u32>(self, k: FieldElement<N>) -> Self {
+        self.mul_scalar(k.val())
+    }
+}
+
 impl<const A: u32, const B: u32, const P: u32> Add for CurvePoint<A, B, P> {
     type Output = Self;
 
@@ -21,13 +348,15 @@ impl<const A: u32, const B: u32, const P: u32> Add for CurvePoint<A, B, P> {
                 CurvePoint::Zero => self,
                 CurvePoint::Point { x: x2, y: y2 } => {
                     if x1 == x2 {
-                        CurvePoint::Zero
+                        // either rhs == self or rhs == -self, checking -self first keeps
+                        // 2-torsion (and every point over F_2) away from doubling
+                        if y1 == -y2 {
+                            CurvePoint::Zero
+                        } else {
+                            self.double()
+                        }
                     } else {
-                        let s = (y1 - y2) / (x1 - x2);
-                        let x = s * s - x1 - x2;
-                        let y = y1 + s * (x2 - x1);
-
-                        CurvePoint::Point { x, y }
+                        self.add_distinct(rhs)
                     }
                 }
             },
@@ -39,12 +368,1051 @@ impl<const A: u32, const B: u32, const P: u32> Sub for CurvePoint<A, B, P> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        match rhs {
-            CurvePoint::Zero => self,
-            CurvePoint::Point { x, y } => {
-                let rhs = CurvePoint::Point { x, y: -y };
-                self + rhs
+        self + -rhs
+    }
+}
+
+impl<const A: u32, const B: u32, const P: u32> Neg for CurvePoint<A, B, P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            CurvePoint::Zero => CurvePoint::Zero,
+            CurvePoint::Point { x, y } => CurvePoint::Point { x, y: -y },
+        }
+    }
+}
+
+impl<const A: u32, const B: u32, const P: u32> Group for CurvePoint<A, B, P> {
+    fn identity() -> Self {
+        CurvePoint::Zero
+    }
+
+    fn mul_scalar(self, k: u32) -> Self {
+        CurvePoint::mul_scalar(self, k)
+    }
+}
+
+// a generator together with its exact order and the cofactor of the subgroup it generates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurveInfo<const A: u32, const B: u32, const P: u32> {
+    order: u32,
+    cofactor: u32,
+    generator: CurvePoint<A, B, P>,
+}
+
+impl<const A: u32, const B: u32, const P: u32> CurveInfo<A, B, P> {
+    // validation counts the curve's points, so only practical for small P
+    pub fn new(
+        order: u32,
+        cofactor: u32,
+        generator: CurvePoint<A, B, P>,
+    ) -> Result<CurveInfo<A, B, P>, ZookError> {
+        if !generator.is_on_curve() {
+            return Err(ZookError::NotOnCurve);
+        }
+        // order must be exact, not just a multiple of the generator's order
+        let is_exact = |q: u32| generator.mul_scalar(order / q).is_point();
+        if order == 0
+            || generator.mul_scalar(order) != CurvePoint::Zero
+            || !ff::prime_factors(order).into_iter().all(is_exact)
+        {
+            return Err(ZookError::WrongOrder);
+        }
+        if u64::from(order) * u64::from(cofactor) != u64::from(CurvePoint::<A, B, P>::curve_order())
+        {
+            return Err(ZookError::WrongCofactor);
+        }
+
+        Ok(CurveInfo {
+            order,
+            cofactor,
+            generator,
+        })
+    }
+
+    pub fn order(&self) -> u32 {
+        self.order
+    }
+
+    pub fn cofactor(&self) -> u32 {
+        self.cofactor
+    }
+
+    pub fn generator(&self) -> CurvePoint<A, B, P> {
+        self.generator
+    }
+}
+
+// a concrete curve that carries its own base point, so callers don't pass it around
+pub trait WeierstrassCurve<const A: u32, const B: u32, const P: u32> {
+    // order of the generator
+    const ORDER: u32;
+    const COFACTOR: u32;
+
+    fn generator() -> CurvePoint<A, B, P>;
+
+    // validates the declared parameters, see CurveInfo::new
+    fn info() -> Result<CurveInfo<A, B, P>, ZookError> {
+        CurveInfo::new(Self::ORDER, Self::COFACTOR, Self::generator())
+    }
+
+    fn mul_generator(k: u32) -> CurvePoint<A, B, P> {
+        Self::generator().mul_scalar(k)
+    }
+}
+
+// fixed-base comb: table[j] holds the sum of 2^(i * spacing) * base over the set bits i of j,
+// so a scalar is processed spacing columns at a time with one doubling per column
+pub struct CombTable<const A: u32, const B: u32, const P: u32> {
+    width: u32,
+    spacing: u32,
+    table: Vec<CurvePoint<A, B, P>>,
+}
+
+impl<const A: u32, const B: u32, const P: u32> CombTable<A, B, P> {
+    pub fn new(base: CurvePoint<A, B, P>, width: u32) -> CombTable<A, B, P> {
+        if !(1..=8).contains(&width) {
+            panic!("comb width must be between 1 and 8")
+        }
+
+        let spacing = u32::BITS.div_ceil(width);
+        let teeth: Vec<_> = (0..width)
+            .map(|i| (0..i * spacing).fold(base, |acm, _| acm.double()))
+            .collect();
+
+        let table = (0..1usize << width)
+            .map(|j| {
+                teeth
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| (j >> i) & 1 == 1)
+                    .fold(CurvePoint::Zero, |acm, (_, &t)| acm + t)
+            })
+            .collect();
+
+        CombTable {
+            width,
+            spacing,
+            table,
+        }
+    }
+
+    pub fn mul(&self, k: u32) -> CurvePoint<A, B, P> {
+        let mut acm = CurvePoint::Zero;
+
+        for col in (0..self.spacing).rev() {
+            acm = acm.double();
+
+            let index = (0..self.width)
+                .filter(|i| i * self.spacing + col < u32::BITS)
+                .filter(|i| (k >> (i * self.spacing + col)) & 1 == 1)
+                .fold(0, |acm, i| acm | (1 << i));
+            acm = acm + self.table[index];
+        }
+
+        acm
+    }
+}
+
+// variable-base multiplication by a fixed point, table[i] holds (2i + 1) * base so repeated
+// calls only pay for the wNAF digits of the scalar
+pub struct WnafContext<const A: u32, const B: u32, const P: u32> {
+    width: u32,
+    table: Vec<CurvePoint<A, B, P>>,
+}
+
+impl<const A: u32, const B: u32, const P: u32> WnafContext<A, B, P> {
+    pub fn new(base: CurvePoint<A, B, P>, width: u32) -> WnafContext<A, B, P> {
+        if !(2..=8).contains(&width) {
+            panic!("wnaf width must be between 2 and 8")
+        }
+
+        let double = base.double();
+        let mut table = vec![base];
+        for i in 1..1usize << (width - 2) {
+            table.push(table[i - 1] + double);
+        }
+
+        WnafContext { width, table }
+    }
+
+    pub fn mul(&self, k: u32) -> CurvePoint<A, B, P> {
+        let mut acm = CurvePoint::Zero;
+        for &d in wnaf_digits(k, self.width).iter().rev() {
+            acm = acm.double();
+            if d > 0 {
+                acm = acm + self.table[(d / 2) as usize];
+            } else if d < 0 {
+                acm = acm - self.table[(-d / 2) as usize];
+            }
+        }
+        acm
+    }
+}
+
+// little-endian digits, each zero or odd with |d| < 2^(width - 1), nonzero digits at least
+// width apart
+fn wnaf_digits(k: u32, width: u32) -> Vec<i64> {
+    let modulus = 1i64 << width;
+    let mut k = i64::from(k);
+    let mut digits = vec![];
+    while k > 0 {
+        let d = if k % 2 == 1 {
+            let d = k.rem_euclid(modulus);
+            if d >= modulus / 2 {
+                d - modulus
+            } else {
+                d
+            }
+        } else {
+            0
+        };
+        digits.push(d);
+        k = (k - d) / 2;
+    }
+    digits
+}
+
+// (k, k * generator) for k in 0..order, for comparing against other implementations
+pub fn scalar_mul_table<const A: u32, const B: u32, const P: u32>(
+    generator: CurvePoint<A, B, P>,
+    order: u32,
+) -> Vec<(u32, CurvePoint<A, B, P>)> {
+    let mut table = Vec::with_capacity(order as usize);
+    let mut cur = CurvePoint::Zero;
+    for k in 0..order {
+        table.push((k, cur));
+        cur = cur + generator;
+    }
+    table
+}
+
+// running sum of curve points
+pub struct PointAccumulator<const A: u32, const B: u32, const P: u32> {
+    acc: CurvePoint<A, B, P>,
+}
+
+impl<const A: u32, const B: u32, const P: u32> PointAccumulator<A, B, P> {
+    pub fn new() -> PointAccumulator<A, B, P> {
+        PointAccumulator {
+            acc: CurvePoint::Zero,
+        }
+    }
+
+    pub fn add_point(&mut self, p: CurvePoint<A, B, P>) {
+        self.acc = self.acc + p;
+    }
+
+    pub fn add_scalar_mul(&mut self, k: u32, base: CurvePoint<A, B, P>) {
+        self.acc = self.acc + base.mul_scalar(k);
+    }
+
+    pub fn finalize(self) -> CurvePoint<A, B, P> {
+        self.acc
+    }
+}
+
+impl<const A: u32, const B: u32, const P: u32> Default for PointAccumulator<A, B, P> {
+    fn default() -> Self {
+        PointAccumulator::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // y^2 = x^3 + 2x + 2 over F_17, a cyclic group of order 19
+    type Point = CurvePoint<2, 2, 17>;
+
+    fn point(x: u32, y: u32) -> Point {
+        CurvePoint::Point {
+            x: FieldElement::new(x),
+            y: FieldElement::new(y),
+        }
+    }
+
+    fn generator() -> Point {
+        point(5, 1)
+    }
+
+    #[test]
+    fn default_is_identity() {
+        assert_eq!(Point::default(), CurvePoint::Zero);
+        assert_eq!(generator() + Point::default(), generator());
+
+        let mut points = vec![generator()];
+        points.resize_with(3, Default::default);
+        assert_eq!(points[2], CurvePoint::Zero);
+    }
+
+    #[test]
+    fn it_tells_identity_from_points() {
+        assert!(Point::Zero.is_zero());
+        assert!(!Point::Zero.is_point());
+        assert!(!generator().is_zero());
+        assert!(generator().is_point());
+    }
+
+    #[test]
+    fn triple_matches_mul_scalar() {
+        for p in generator().subgroup_elements() {
+            assert_eq!(p.triple(), p.mul_scalar(3));
+        }
+        for x in 0..7 {
+            for p in CurvePoint::<1, 0, 7>::points_with_x(FieldElement::new(x)) {
+                assert_eq!(p.triple(), p.mul_scalar(3));
+            }
+        }
+    }
+
+    #[test]
+    fn add_distinct_matches_add() {
+        let elements: Vec<_> = generator().subgroup_elements().collect();
+        for &p in elements.iter().filter(|p| p.is_point()) {
+            for &q in elements.iter().filter(|q| q.is_point()) {
+                if let (CurvePoint::Point { x: x1, .. }, CurvePoint::Point { x: x2, .. }) = (p, q) {
+                    if x1 != x2 {
+                        assert_eq!(p.add_distinct(q), p + q);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "add_distinct requires distinct x coordinates")]
+    fn add_distinct_rejects_equal_x() {
+        let _p = generator().add_distinct(-generator());
+    }
+
+    #[test]
+    #[should_panic(expected = "add_distinct requires two points other than Zero")]
+    fn add_distinct_rejects_zero() {
+        let _p = generator().add_distinct(Point::Zero);
+    }
+
+    #[test]
+    fn subtracting_a_point_from_itself_is_zero() {
+        for p in generator().subgroup_elements() {
+            assert_eq!(p - p, Point::Zero);
+        }
+    }
+
+    #[test]
+    fn subtracting_2_torsion_from_itself_is_zero() {
+        // y^2 = x^3 + x over F_7, (0, 0) is its own negation
+        let t = CurvePoint::<1, 0, 7>::Point {
+            x: FieldElement::new(0),
+            y: FieldElement::new(0),
+        };
+        assert_eq!(-t, t);
+        assert_eq!(t - t, CurvePoint::Zero);
+        assert_eq!(t + t, CurvePoint::Zero);
+
+        for x in 0..7 {
+            for p in CurvePoint::<1, 0, 7>::points_with_x(FieldElement::new(x)) {
+                assert_eq!(p - p, CurvePoint::Zero);
+            }
+        }
+    }
+
+    #[test]
+    fn subtracting_a_point_from_itself_over_f2_is_zero() {
+        // every point over F_2 has y == -y, this used to reach a doubling panic
+        for x in 0..2 {
+            for p in CurvePoint::<1, 1, 2>::points_with_x(FieldElement::new(x)) {
+                assert_eq!(p - p, CurvePoint::Zero);
+            }
+        }
+    }
+
+    #[test]
+    fn windowed_multiplication_matches_double_and_add() {
+        for k in 0..200 {
+            for window in 1..=8 {
+                assert_eq!(
+                    generator().mul_scalar_windowed(k, window),
+                    generator().mul_scalar(k)
+                );
+            }
+            assert_eq!(generator().mul_scalar_auto(k), generator().mul_scalar(k));
+        }
+        for k in [65535, 1 << 20, 0xdead_beef, u32::MAX] {
+            assert_eq!(generator().mul_scalar_auto(k), generator().mul_scalar(k));
+        }
+        assert_eq!(Point::Zero.mul_scalar_auto(12345), Point::Zero);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be between 1 and 8")]
+    fn windowed_multiplication_rejects_large_windows() {
+        let _p = generator().mul_scalar_windowed(5, 9);
+    }
+
+    #[test]
+    fn assert_valid_accepts_subgroup_points() {
+        for p in generator().subgroup_elements() {
+            p.assert_valid(19);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "point is not on the curve")]
+    fn assert_valid_rejects_points_off_the_curve() {
+        point(5, 2).assert_valid(19);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "point is not in the subgroup of the given order")]
+    fn assert_valid_rejects_points_outside_the_subgroup() {
+        // the generator has order 19, not 7
+        generator().assert_valid(7);
+    }
+
+    #[test]
+    fn all_on_curve_rejects_a_single_bad_point() {
+        let mut points: Vec<_> = generator().subgroup_elements().collect();
+        assert!(Point::all_on_curve(&points));
+        assert!(Point::all_on_curve(&[]));
+
+        points.insert(7, point(5, 2));
+        assert!(!Point::all_on_curve(&points));
+    }
+
+    #[test]
+    fn new_rejects_points_off_the_curve() {
+        assert_eq!(
+            Point::new(FieldElement::new(5), FieldElement::new(1)),
+            Ok(generator())
+        );
+        assert_eq!(
+            Point::new(FieldElement::new(5), FieldElement::new(2)),
+            Err(ZookError::NotOnCurve)
+        );
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        assert_eq!(Point::COORD_BYTES, 1);
+        assert_eq!(generator().to_bytes(), vec![1, 5, 1]);
+        assert_eq!(Point::Zero.to_bytes(), vec![0]);
+        for p in generator().subgroup_elements() {
+            assert_eq!(Point::from_bytes(&p.to_bytes()), Ok(p));
+        }
+    }
+
+    #[test]
+    fn from_bytes_rejects_invalid_encodings() {
+        assert_eq!(Point::from_bytes(&[]), Err(ZookError::LengthMismatch));
+        assert_eq!(Point::from_bytes(&[0, 0]), Err(ZookError::LengthMismatch));
+        assert_eq!(Point::from_bytes(&[1, 5]), Err(ZookError::LengthMismatch));
+        assert_eq!(
+            Point::from_bytes(&[2, 5, 1]),
+            Err(ZookError::InvalidEncoding)
+        );
+        assert_eq!(
+            Point::from_bytes(&[1, 22, 1]),
+            Err(ZookError::InvalidEncoding)
+        );
+        assert_eq!(Point::from_bytes(&[1, 5, 2]), Err(ZookError::NotOnCurve));
+    }
+
+    #[test]
+    fn eq_point_survives_jacobian_round_trip() {
+        for p in generator().subgroup_elements() {
+            for l in 1..17 {
+                let round_trip = jacobian::JacobianPoint::from_affine(p)
+                    .rescale(FieldElement::new(l))
+                    .to_affine();
+                assert!(p.eq_point(&round_trip));
+                assert_eq!(p, round_trip);
+            }
+            assert!(!p.eq_point(&(p + generator())));
+        }
+    }
+
+    #[test]
+    fn it_doubles() {
+        assert_eq!(generator().double(), point(6, 3));
+        assert_eq!(generator() + generator(), point(6, 3));
+    }
+
+    #[test]
+    fn doubling_over_f2_matches_addition() {
+        // every point over F_2 is its own negation
+        for x in 0..2 {
+            for p in CurvePoint::<0, 1, 2>::points_with_x(FieldElement::new(x)) {
+                assert_eq!(p.double(), CurvePoint::Zero);
+                assert_eq!(p.double(), p + p);
             }
         }
     }
+
+    #[test]
+    fn mul_scalar_over_f2_alternates_between_p_and_zero() {
+        for x in 0..2 {
+            for p in CurvePoint::<0, 1, 2>::points_with_x(FieldElement::new(x)) {
+                for k in 0..10 {
+                    let expected = if k % 2 == 1 { p } else { CurvePoint::Zero };
+                    assert_eq!(p.mul_scalar(k), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn curve_order_over_f2_counts_each_point_once() {
+        // y^2 = x^3 + 1 over F_2 is O, (0, 1) and (1, 0)
+        assert_eq!(CurvePoint::<0, 1, 2>::curve_order(), 3);
+    }
+
+    #[test]
+    fn points_with_x_over_f2_has_no_duplicates() {
+        // y^2 = x^3 + 1 over F_2, x = 0 gives y = 1 = -1
+        assert_eq!(
+            CurvePoint::<0, 1, 2>::points_with_x(FieldElement::new(0)).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn it_adds_distinct_points() {
+        assert_eq!(point(5, 1) + point(6, 3), point(10, 6));
+    }
+
+    #[test]
+    fn point_plus_its_negation_is_zero() {
+        assert_eq!(point(5, 1) + point(5, 16), CurvePoint::Zero);
+        assert_eq!(point(5, 1) - point(5, 1), CurvePoint::Zero);
+    }
+
+    #[test]
+    fn negation_composes_with_addition_and_subtraction() {
+        assert_eq!(-Point::Zero, Point::Zero);
+        for p in generator().subgroup_elements() {
+            assert_eq!(-(-p), p);
+            assert_eq!(p + -p, Point::Zero);
+            assert_eq!(-p + p, Point::Zero);
+            assert_eq!(Point::Zero - p, -p);
+            assert_eq!(p - Point::Zero, p);
+            assert!((-p).is_on_curve());
+        }
+        for x in 0..7 {
+            for p in CurvePoint::<1, 0, 7>::points_with_x(FieldElement::new(x)) {
+                assert_eq!(-(-p), p);
+                assert_eq!(p + -p, CurvePoint::Zero);
+                assert_eq!(CurvePoint::Zero - p, -p);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_is_identity_element() {
+        assert_eq!(generator() + CurvePoint::Zero, generator());
+        assert_eq!(CurvePoint::Zero + generator(), generator());
+    }
+
+    #[test]
+    fn mul_scalar_matches_repeated_addition() {
+        let mut expected = CurvePoint::Zero;
+        for k in 0..40 {
+            assert_eq!(generator().mul_scalar(k), expected);
+            expected = expected + generator();
+        }
+    }
+
+    #[test]
+    fn mul_scalar_bits_matches_mul_scalar() {
+        for k in 0..19 {
+            let bits = FieldElement::<19>::new(k).to_bits_le();
+            assert_eq!(
+                generator().mul_scalar_bits(&bits),
+                generator().mul_scalar(k)
+            );
+        }
+        assert_eq!(generator().mul_scalar_bits(&[]), CurvePoint::Zero);
+    }
+
+    #[test]
+    fn scalar_mul_is_homomorphic() {
+        test_support::assert_scalar_mul_homomorphism(generator(), 19);
+        // (0, 0) generates a subgroup of order 2 on y^2 = x^3 + x over F_7
+        let two_torsion = CurvePoint::<1, 0, 7>::Point {
+            x: FieldElement::new(0),
+            y: FieldElement::new(0),
+        };
+        test_support::assert_scalar_mul_homomorphism(two_torsion, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "* base !=")]
+    fn homomorphism_check_catches_wrong_order() {
+        test_support::assert_scalar_mul_homomorphism(generator(), 17);
+    }
+
+    #[test]
+    fn generator_has_order_19() {
+        assert_eq!(generator().mul_scalar(19), CurvePoint::Zero);
+        assert_ne!(generator().mul_scalar(18), CurvePoint::Zero);
+    }
+
+    #[test]
+    fn mul_scalar_fe_matches_mul_scalar() {
+        for k in 0..19 {
+            let scalar = FieldElement::<19>::new(k);
+            assert_eq!(generator().mul_scalar_fe(scalar), generator().mul_scalar(k));
+        }
+    }
+
+    #[test]
+    fn mul_scalar_fe_wraps_mod_order() {
+        let scalar = FieldElement::<19>::new(19 + 4);
+        assert_eq!(generator().mul_scalar_fe(scalar), generator().mul_scalar(4));
+        assert_eq!(
+            generator().mul_scalar_fe(scalar),
+            generator().mul_scalar(23)
+        );
+    }
+
+    #[test]
+    fn to_scalar_recovers_discrete_log() {
+        for k in 0..19 {
+            assert_eq!(
+                generator().mul_scalar(k).to_scalar(generator(), 19),
+                Some(k)
+            );
+        }
+    }
+
+    #[test]
+    fn to_scalar_fails_outside_subgroup() {
+        // the subgroup generated by the identity is trivial
+        assert_eq!(generator().to_scalar(CurvePoint::Zero, 1), None);
+        assert_eq!(CurvePoint::Zero.to_scalar(generator(), 19), Some(0));
+    }
+
+    #[test]
+    fn points_with_x_returns_both_roots() {
+        let points = Point::points_with_x(FieldElement::new(5));
+
+        assert_eq!(points.len(), 2);
+        assert!(points.contains(&point(5, 1)));
+        assert!(points.contains(&point(5, 16)));
+    }
+
+    #[test]
+    fn points_with_x_returns_nothing_for_non_residue() {
+        // x = 2 gives 8 + 4 + 2 = 14, a non-residue mod 17
+        assert!(Point::points_with_x(FieldElement::new(2)).is_empty());
+    }
+
+    #[test]
+    fn points_with_x_returns_single_point_for_zero_rhs() {
+        // y^2 = x^3 + x over F_7 passes through (0, 0)
+        let points = CurvePoint::<1, 0, 7>::points_with_x(FieldElement::new(0));
+        assert_eq!(
+            points,
+            vec![CurvePoint::Point {
+                x: FieldElement::new(0),
+                y: FieldElement::new(0)
+            }]
+        );
+    }
+
+    #[test]
+    fn lift_x_picks_the_even_y() {
+        for x in 0..17 {
+            let x = FieldElement::new(x);
+            let points = Point::points_with_x(x);
+            match Point::lift_x(x) {
+                Some(p @ CurvePoint::Point { y, .. }) => {
+                    assert!(y.val().is_multiple_of(2));
+                    assert!(points.contains(&p));
+                }
+                Some(CurvePoint::Zero) => unreachable!(),
+                None => assert!(points.is_empty()),
+            }
+        }
+        // (5, 1) has odd y so lifting 5 gives its negation
+        assert_eq!(Point::lift_x(FieldElement::new(5)), Some(point(5, 16)));
+        assert_eq!(Point::lift_x(FieldElement::new(2)), None);
+    }
+
+    #[test]
+    fn points_with_x_enumerates_the_whole_group() {
+        let count: usize = (0..17)
+            .map(|x| Point::points_with_x(FieldElement::new(x)).len())
+            .sum();
+        // every point except the identity
+        assert_eq!(count, 18);
+    }
+
+    #[test]
+    fn subgroup_elements_cycles_through_the_order() {
+        let elements: Vec<_> = generator().subgroup_elements().collect();
+
+        assert_eq!(elements.len(), 19);
+        assert_eq!(elements[0], CurvePoint::Zero);
+        assert_eq!(elements[1], generator());
+        assert_eq!(*elements.last().unwrap(), -generator());
+    }
+
+    #[test]
+    fn subgroup_of_zero_is_trivial() {
+        let elements: Vec<_> = Point::Zero.subgroup_elements().collect();
+        assert_eq!(elements, vec![CurvePoint::Zero]);
+    }
+
+    #[test]
+    fn scalar_mul_table_steps_by_generator() {
+        let table = scalar_mul_table(generator(), 19);
+
+        assert_eq!(table.len(), 19);
+        assert_eq!(table[0], (0, CurvePoint::Zero));
+        for pair in table.windows(2) {
+            assert_eq!(pair[1].0, pair[0].0 + 1);
+            assert_eq!(pair[1].1, pair[0].1 + generator());
+        }
+        for (k, p) in table {
+            assert_eq!(p, generator().mul_scalar(k));
+        }
+    }
+
+    #[test]
+    fn curve_operations_complete_within_loose_bound() {
+        let start = std::time::Instant::now();
+        for k in 0..10_000 {
+            let _p = generator().mul_scalar(k) + generator().double();
+        }
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn add_many_matches_sequential_addition() {
+        let points: Vec<_> = (0..40).map(|k| generator().mul_scalar(k * 7 + 3)).collect();
+
+        for start in [CurvePoint::Zero, generator(), point(6, 3)] {
+            for len in 0..points.len() {
+                let expected = points[..len].iter().fold(start, |acm, &p| acm + p);
+                assert_eq!(Point::add_many(start, &points[..len]), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn add_many_handles_exceptional_pairs() {
+        let g = generator();
+        // doublings, inverses and identities all fall back to plain addition
+        let points = [g, g, CurvePoint::Zero, -g, g, g.double(), -g];
+        let expected = points.iter().fold(g, |acm, &p| acm + p);
+        assert_eq!(Point::add_many(g, &points), expected);
+    }
+
+    mod group_trait {
+        use super::*;
+
+        // Schnorr identification: prove knowledge of x with public = x * g
+        fn schnorr_accepts<G: Group>(g: G, order: u32, x: u32, r: u32, c: u32) -> bool {
+            let public = g.mul_scalar(x);
+            let commitment = g.mul_scalar(r);
+            let response = (r + c * x) % order;
+
+            g.mul_scalar(response) == commitment + public.mul_scalar(c)
+        }
+
+        #[test]
+        fn generic_schnorr_runs_over_curve() {
+            for x in 1..19 {
+                assert!(schnorr_accepts(generator(), 19, x, 7, 11));
+            }
+        }
+
+        #[test]
+        fn identity_is_zero() {
+            assert_eq!(<Point as Group>::identity(), CurvePoint::Zero);
+        }
+
+        #[test]
+        fn negation_is_additive_inverse() {
+            assert_eq!(-generator(), point(5, 16));
+            assert_eq!(generator() + -generator(), CurvePoint::Zero);
+            assert_eq!(-Point::Zero, CurvePoint::Zero);
+        }
+    }
+
+    #[test]
+    fn it_checks_curve_membership() {
+        assert!(generator().is_on_curve());
+        assert!(Point::Zero.is_on_curve());
+        assert!(!point(5, 2).is_on_curve());
+    }
+
+    #[test]
+    fn it_counts_curve_points() {
+        assert_eq!(Point::curve_order(), 19);
+        // y^2 = x^3 + x over F_7 has 8 points
+        assert_eq!(CurvePoint::<1, 0, 7>::curve_order(), 8);
+    }
+
+    mod degenerate_coefficients {
+        use super::*;
+
+        fn all_points<const A: u32, const B: u32, const P: u32>() -> Vec<CurvePoint<A, B, P>> {
+            let mut points = vec![CurvePoint::Zero];
+            for x in 0..P {
+                points.extend(CurvePoint::points_with_x(FieldElement::new(x)));
+            }
+            points
+        }
+
+        fn assert_group_law<const A: u32, const B: u32, const P: u32>() {
+            let points = all_points::<A, B, P>();
+            assert_eq!(points.len() as u32, CurvePoint::<A, B, P>::curve_order());
+
+            for &p in &points {
+                assert_eq!(p + -p, CurvePoint::Zero);
+                assert_eq!(p.double(), p + p);
+                for &q in &points {
+                    assert!((p + q).is_on_curve());
+                    assert_eq!(p + q, q + p);
+                    for &r in &points {
+                        assert_eq!((p + q) + r, p + (q + r));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn group_law_holds_with_a_zero() {
+            // y^2 = x^3 + 3 over F_13
+            assert_group_law::<0, 3, 13>();
+        }
+
+        #[test]
+        fn group_law_holds_with_b_zero() {
+            // y^2 = x^3 + x over F_7
+            assert_group_law::<1, 0, 7>();
+        }
+
+        #[test]
+        fn inflection_points_have_order_3() {
+            // on y^2 = x^3 + B the points with x = 0 are inflection points
+            for p in CurvePoint::<0, 3, 13>::points_with_x(FieldElement::new(0)) {
+                assert_eq!(p.double(), -p);
+                assert_eq!(p.mul_scalar(3), CurvePoint::Zero);
+            }
+        }
+
+        #[test]
+        fn two_torsion_points_double_to_zero() {
+            // x^3 + x = x(x^2 + 1) and x^2 + 1 is irreducible mod 7, so (0, 0) is the only one
+            let torsion: Vec<_> = all_points::<1, 0, 7>()
+                .into_iter()
+                .filter(|p| matches!(p, CurvePoint::Point { y, .. } if y.val() == 0))
+                .collect();
+
+            assert_eq!(torsion.len(), 1);
+            for p in torsion {
+                assert_eq!(p.double(), CurvePoint::Zero);
+                assert_eq!(-p, p);
+            }
+        }
+    }
+
+    mod comb {
+        use super::*;
+
+        #[test]
+        fn comb_matches_mul_scalar() {
+            let comb = CombTable::new(generator(), 4);
+            for k in (0..300).chain([u32::MAX, u32::MAX - 18, 1 << 31]) {
+                assert_eq!(comb.mul(k), generator().mul_scalar(k));
+            }
+        }
+
+        #[test]
+        fn comb_widths_agree() {
+            let combs: Vec<_> = (1..=8).map(|w| CombTable::new(generator(), w)).collect();
+            for k in [0, 1, 18, 19, 12345, u32::MAX] {
+                let expected = generator().mul_scalar(k);
+                for comb in &combs {
+                    assert_eq!(comb.mul(k), expected);
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "comb width must be between 1 and 8")]
+        fn it_rejects_zero_width() {
+            let _comb = CombTable::new(generator(), 0);
+        }
+    }
+
+    mod wnaf {
+        use super::*;
+
+        #[test]
+        fn wnaf_digits_recompose_the_scalar() {
+            for width in 2..=8 {
+                for k in (0..500).chain([u32::MAX, 1 << 31]) {
+                    let digits = wnaf_digits(k, width);
+                    let value = digits.iter().rev().fold(0i64, |acm, &d| 2 * acm + d);
+                    assert_eq!(value, i64::from(k));
+                    assert!(digits
+                        .iter()
+                        .all(|&d| d == 0 || (d % 2 != 0 && d.abs() < 1 << (width - 1))));
+                }
+            }
+        }
+
+        #[test]
+        fn wnaf_matches_mul_scalar() {
+            for width in 2..=8 {
+                let ctx = WnafContext::new(generator(), width);
+                for k in (0..100).chain([12345, 1 << 31, u32::MAX]) {
+                    assert_eq!(ctx.mul(k), generator().mul_scalar(k));
+                }
+            }
+        }
+
+        #[test]
+        fn reused_context_matches_fresh_context() {
+            let ctx = WnafContext::new(generator(), 4);
+            for k in [7, 19, 7, u32::MAX, 19] {
+                assert_eq!(ctx.mul(k), WnafContext::new(generator(), 4).mul(k));
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "wnaf width must be between 2 and 8")]
+        fn it_rejects_width_one() {
+            let _ctx = WnafContext::new(generator(), 1);
+        }
+    }
+
+    mod curve_info {
+        use super::*;
+
+        // y^2 = x^3 + 2x + 2 over F_17
+        struct Curve17;
+
+        impl WeierstrassCurve<2, 2, 17> for Curve17 {
+            const ORDER: u32 = 19;
+            const COFACTOR: u32 = 1;
+
+            fn generator() -> Point {
+                point(5, 1)
+            }
+        }
+
+        #[test]
+        fn trait_generator_has_the_declared_order() {
+            let g = Curve17::generator();
+            assert!(g.is_on_curve());
+            assert_eq!(g.mul_scalar(Curve17::ORDER), CurvePoint::Zero);
+            assert!((1..Curve17::ORDER).all(|k| g.mul_scalar(k).is_point()));
+            assert_eq!(Curve17::info().unwrap().generator(), g);
+        }
+
+        #[test]
+        fn mul_generator_uses_the_trait_generator() {
+            for k in 0..40 {
+                assert_eq!(Curve17::mul_generator(k), generator().mul_scalar(k));
+            }
+        }
+
+        #[test]
+        fn it_accepts_consistent_parameters() {
+            let info = CurveInfo::new(19, 1, generator()).unwrap();
+            assert_eq!(info.order(), 19);
+            assert_eq!(info.cofactor(), 1);
+            assert_eq!(info.generator(), generator());
+        }
+
+        #[test]
+        fn it_rejects_off_curve_generator() {
+            assert_eq!(
+                CurveInfo::new(19, 1, point(5, 2)),
+                Err(ZookError::NotOnCurve)
+            );
+        }
+
+        #[test]
+        fn it_rejects_inconsistent_order() {
+            assert_eq!(
+                CurveInfo::new(17, 1, generator()),
+                Err(ZookError::WrongOrder)
+            );
+            assert_eq!(
+                CurveInfo::new(0, 1, generator()),
+                Err(ZookError::WrongOrder)
+            );
+        }
+
+        #[test]
+        fn it_rejects_multiples_of_the_generator_order() {
+            // (0, 0) on y^2 = x^3 + x over F_7 has order 2 in a group of 8 points
+            let t = CurvePoint::<1, 0, 7>::Point {
+                x: FieldElement::new(0),
+                y: FieldElement::new(0),
+            };
+            assert_eq!(CurveInfo::new(8, 1, t), Err(ZookError::WrongOrder));
+            assert_eq!(CurveInfo::new(4, 2, t), Err(ZookError::WrongOrder));
+            assert!(CurveInfo::new(2, 4, t).is_ok());
+            assert_eq!(
+                CurveInfo::new(38, 1, generator()),
+                Err(ZookError::WrongOrder)
+            );
+        }
+
+        #[test]
+        fn it_rejects_inconsistent_cofactor() {
+            assert_eq!(
+                CurveInfo::new(19, 2, generator()),
+                Err(ZookError::WrongCofactor)
+            );
+        }
+
+        #[test]
+        fn it_accepts_subgroup_with_cofactor() {
+            // (0, 0) has order 2 on y^2 = x^3 + x over F_7, a curve of order 8
+            let two_torsion = CurvePoint::<1, 0, 7>::Point {
+                x: FieldElement::new(0),
+                y: FieldElement::new(0),
+            };
+            assert!(CurveInfo::new(2, 4, two_torsion).is_ok());
+        }
+    }
+
+    mod accumulator {
+        use super::*;
+
+        #[test]
+        fn it_starts_at_zero() {
+            assert_eq!(
+                PointAccumulator::<2, 2, 17>::new().finalize(),
+                CurvePoint::Zero
+            );
+        }
+
+        #[test]
+        fn accumulating_equals_direct_sum() {
+            let points = [point(5, 1), point(6, 3), point(10, 6), point(3, 1)];
+            let mut acc = PointAccumulator::new();
+            for p in points {
+                acc.add_point(p);
+            }
+            acc.add_scalar_mul(7, generator());
+
+            let direct = points
+                .into_iter()
+                .fold(generator().mul_scalar(7), |acm, p| acm + p);
+            assert_eq!(acc.finalize(), direct);
+        }
+    }
 }
@@ -0,0 +1,125 @@
+use super::jacobian::{self, JacobianPoint};
+use super::CurvePoint;
+use crate::error::ZookError;
+
+// Pippenger's bucket method: sum of scalars[i] * bases[i]
+pub fn msm<const A: u32, const B: u32, const P: u32>(
+    bases: &[CurvePoint<A, B, P>],
+    scalars: &[u32],
+) -> Result<CurvePoint<A, B, P>, ZookError> {
+    if bases.len() != scalars.len() {
+        return Err(ZookError::LengthMismatch);
+    }
+
+    let c = window_bits(bases.len());
+    let windows = u32::BITS.div_ceil(c);
+    let mut acm = CurvePoint::Zero;
+
+    for w in (0..windows).rev() {
+        for _ in 0..c {
+            acm = acm.double();
+        }
+
+        // sum_j j * B_j as a running suffix sum
+        let mut running = CurvePoint::Zero;
+        let mut window_sum = CurvePoint::Zero;
+        for &bucket in bucket_sums(bases, scalars, w * c, c).iter().rev() {
+            running = running + bucket;
+            window_sum = window_sum + running;
+        }
+        acm = acm + window_sum;
+    }
+
+    Ok(acm)
+}
+
+// roughly log2(n), the usual Pippenger window
+fn window_bits(n: usize) -> u32 {
+    match n {
+        0..=3 => 1,
+        _ => n.ilog2().min(16),
+    }
+}
+
+// buckets[j - 1] holds the bases whose c-bit digit starting at `shift` is j, accumulated in
+// Jacobian coordinates and converted to affine together at the end
+pub(crate) fn bucket_sums<const A: u32, const B: u32, const P: u32>(
+    bases: &[CurvePoint<A, B, P>],
+    scalars: &[u32],
+    shift: u32,
+    c: u32,
+) -> Vec<CurvePoint<A, B, P>> {
+    let mut buckets = vec![JacobianPoint::identity(); (1 << c) - 1];
+    for (&base, &k) in bases.iter().zip(scalars) {
+        let digit = (u64::from(k) >> shift) & ((1 << c) - 1);
+        if digit != 0 {
+            let bucket = &mut buckets[digit as usize - 1];
+            *bucket = bucket.add(JacobianPoint::from_affine(base));
+        }
+    }
+    jacobian::batch_to_affine(&buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ff::FieldElement;
+
+    type Point = CurvePoint<2, 2, 17>;
+
+    fn generator() -> Point {
+        Point::Point {
+            x: FieldElement::new(5),
+            y: FieldElement::new(1),
+        }
+    }
+
+    // deterministic stand-in for random scalars
+    fn scalars(n: usize) -> Vec<u32> {
+        let mut state = 0x2545_f491_u32;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                state
+            })
+            .collect()
+    }
+
+    fn naive(bases: &[Point], scalars: &[u32]) -> Point {
+        bases
+            .iter()
+            .zip(scalars)
+            .fold(Point::Zero, |acm, (b, &k)| acm + b.mul_scalar(k))
+    }
+
+    #[test]
+    fn msm_matches_naive_weighted_sum() {
+        let elements: Vec<_> = generator().subgroup_elements().collect();
+        for n in [0, 1, 2, 5, 19, 40] {
+            let bases: Vec<_> = (0..n).map(|i| elements[(i * 7) % elements.len()]).collect();
+            let scalars = scalars(n);
+            assert_eq!(msm(&bases, &scalars), Ok(naive(&bases, &scalars)));
+        }
+    }
+
+    #[test]
+    fn msm_handles_extreme_scalars() {
+        let bases = vec![generator(), generator().double(), Point::Zero];
+        let scalars = vec![u32::MAX, 0, 12345];
+        assert_eq!(msm(&bases, &scalars), Ok(naive(&bases, &scalars)));
+    }
+
+    #[test]
+    fn msm_rejects_length_mismatch() {
+        assert_eq!(msm(&[generator()], &[]), Err(ZookError::LengthMismatch));
+    }
+
+    #[test]
+    fn bucket_sums_group_bases_by_digit() {
+        let g = generator();
+        let h = g.double();
+        // digits of the low 2 bits: 1, 3, 1, 0
+        let buckets = bucket_sums(&[g, h, h, g], &[5, 7, 1, 4], 0, 2);
+        assert_eq!(buckets, vec![g + h, Point::Zero, h]);
+    }
+}
@@ -0,0 +1,27 @@
+use super::CurvePoint;
+
+// checks k -> k * base is a ring homomorphism from scalars mod order, for every pair of scalars
+pub(crate) fn assert_scalar_mul_homomorphism<const A: u32, const B: u32, const P: u32>(
+    base: CurvePoint<A, B, P>,
+    order: u32,
+) {
+    let reduce = |k: u64| (k % u64::from(order)) as u32;
+
+    for a in 0..order {
+        for b in 0..order {
+            let sum = reduce(u64::from(a) + u64::from(b));
+            let product = reduce(u64::from(a) * u64::from(b));
+
+            assert_eq!(
+                base.mul_scalar(sum),
+                base.mul_scalar(a) + base.mul_scalar(b),
+                "({a} + {b}) * base != {a} * base + {b} * base"
+            );
+            assert_eq!(
+                base.mul_scalar(product),
+                base.mul_scalar(a).mul_scalar(b),
+                "({a} * {b}) * base != {b} * ({a} * base)"
+            );
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::error::Error;
+use std::fmt;
+
+// shared by every fallible API in the crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZookError {
+    ModuloZero,
+    DivisionByZero,
+    NoMultiplicativeInverse,
+    NotOnCurve,
+    InvalidEncoding,
+    LengthMismatch,
+    WrongOrder,
+    WrongCofactor,
+}
+
+impl fmt::Display for ZookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ZookError::ModuloZero => "modulus can't be 0",
+            ZookError::DivisionByZero => "division by 0",
+            ZookError::NoMultiplicativeInverse => "gcd(a,n) != 1",
+            ZookError::NotOnCurve => "point is not on the curve",
+            ZookError::InvalidEncoding => "invalid encoding",
+            ZookError::LengthMismatch => "length mismatch",
+            ZookError::WrongOrder => "order doesn't match the generator",
+            ZookError::WrongCofactor => "order times cofactor doesn't match the curve order",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for ZookError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_displays_lowercase_messages() {
+        assert_eq!(ZookError::DivisionByZero.to_string(), "division by 0");
+        assert_eq!(
+            ZookError::NotOnCurve.to_string(),
+            "point is not on the curve"
+        );
+        assert_eq!(
+            ZookError::WrongOrder.to_string(),
+            "order doesn't match the generator"
+        );
+    }
+
+    #[test]
+    fn it_is_a_std_error() {
+        let err: Box<dyn Error> = Box::new(ZookError::InvalidEncoding);
+        assert_eq!(err.to_string(), "invalid encoding");
+    }
+}
@@ -1,16 +1,31 @@
+use std::iter::Sum;
 use std::num::NonZeroU32;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub struct FieldElement<const P: u32> {
-    val: u32,
+use crate::error::ZookError;
+
+mod split;
+mod table;
+
+pub use split::SplitFieldElement;
+pub use table::FieldTable;
+
+pub trait Field: Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Copy {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn inverse(self) -> Option<Self>;
+    fn pow(self, e: u64) -> Self;
 }
 
-enum ModularArithmeticError {
-    NoMultiplicativeInverse,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldElement<const P: u32> {
+    val: u32,
 }
 
 impl<const P: u32> FieldElement<P> {
+    // bytes needed for the largest reduced value P - 1
+    pub const COORD_BYTES: usize = ((u32::BITS - (P - 1).leading_zeros()) as usize).div_ceil(8);
+
     pub fn new(val: u32) -> FieldElement<P> {
         if P == 0 {
             panic!("FieldElement can't have 0 as a modulo")
@@ -18,14 +33,493 @@ impl<const P: u32> FieldElement<P> {
         FieldElement { val: val % P }
     }
 
+    /// Stores `val` without reducing it. Passing `val >= P` is not undefined behaviour, but the
+    /// element is non-canonical and arithmetic on it is wrong; debug builds assert against it.
+    pub const fn new_unchecked(val: u32) -> FieldElement<P> {
+        debug_assert!(val < P, "new_unchecked value out of range");
+        FieldElement { val }
+    }
+
+    /// Strict counterpart of `From<u32>`: rejects `val >= P` instead of reducing it.
+    ///
+    /// `TryFrom<u32>` is taken by the standard blanket impl over `From<u32>` and never fails,
+    /// so validation goes through this constructor.
+    pub fn try_new(val: u32) -> Result<FieldElement<P>, ZookError> {
+        if P == 0 {
+            Err(ZookError::ModuloZero)
+        } else if val < P {
+            Ok(FieldElement::new(val))
+        } else {
+            Err(ZookError::InvalidEncoding)
+        }
+    }
+
+    // negative values wrap around, e.g. -1 becomes P - 1
+    pub fn from_i64(val: i64) -> FieldElement<P> {
+        Self {
+            val: reduce_signed(
+                val,
+                NonZeroU32::new(P).expect("FieldElement can't have 0 as a modulo"),
+            ),
+        }
+    }
+
+    pub fn zero() -> FieldElement<P> {
+        FieldElement::new(0)
+    }
+
+    pub fn one() -> FieldElement<P> {
+        FieldElement::new(1)
+    }
+
+    pub const fn characteristic() -> u32 {
+        P
+    }
+
+    pub const fn extension_degree() -> u32 {
+        1
+    }
+
     pub fn val(&self) -> u32 {
         self.val
     }
 
-    pub fn pow(self, rhs: Self) -> Self {
+    pub fn add_mod(self, rhs: Self) -> Self {
+        Self {
+            val: modulus_add(self.val, rhs.val, NonZeroU32::new(P).unwrap()),
+        }
+    }
+
+    pub fn sub_mod(self, rhs: Self) -> Self {
+        Self {
+            val: modulus_sub(self.val, rhs.val, NonZeroU32::new(P).unwrap()),
+        }
+    }
+
+    pub fn mul_mod(self, rhs: Self) -> Self {
+        Self {
+            val: modulus_mul(self.val, rhs.val, NonZeroU32::new(P).unwrap()),
+        }
+    }
+
+    // self * from_i64(k), multiplies by |k| and negates the product for negative k
+    pub fn mul_i64(self, k: i64) -> Self {
+        let magnitude = (k.unsigned_abs() % u64::from(P)) as u32;
+        let product = self.mul_mod(Self::new(magnitude));
+        if k < 0 {
+            -product
+        } else {
+            product
+        }
+    }
+
+    // self * new(k), small k are done with additions instead of a u128 multiply
+    pub fn mul_by_u32(self, k: u32) -> Self {
+        match k {
+            0 => Self::zero(),
+            1 => self,
+            2 => self + self,
+            3 => self + self + self,
+            4 => {
+                let x2 = self + self;
+                x2 + x2
+            }
+            5 => {
+                let x2 = self + self;
+                x2 + x2 + self
+            }
+            6 => {
+                let x3 = self + self + self;
+                x3 + x3
+            }
+            7 => {
+                let x2 = self + self;
+                let x4 = x2 + x2;
+                x4 + x2 + self
+            }
+            8 => {
+                let x2 = self + self;
+                let x4 = x2 + x2;
+                x4 + x4
+            }
+            _ => self.mul_mod(Self::new(k)),
+        }
+    }
+
+    pub fn negate(&mut self) {
+        *self = -*self;
+    }
+
+    pub fn neg_assign(&mut self) {
+        self.negate();
+    }
+
+    // the normalized form used for comparisons across representations, plain elements
+    // are always canonical
+    pub fn canonical(self) -> Self {
+        self
+    }
+
+    // self == new(k) without building the element
+    pub fn eq_u32(self, k: u32) -> bool {
+        self.val == k % P
+    }
+
+    pub fn to_u64(self) -> u64 {
+        u64::from(self.val)
+    }
+
+    pub fn to_usize(self) -> usize {
+        self.val as usize
+    }
+
+    // ceil(log2(P)) bits, least significant first
+    pub fn to_bits_le(self) -> Vec<bool> {
+        let len = u32::BITS - (P - 1).leading_zeros();
+        (0..len).map(|i| (self.val >> i) & 1 == 1).collect()
+    }
+
+    // i-th least significant bit of the canonical value, false past the 32nd
+    pub fn bit(self, i: u32) -> bool {
+        self.val.checked_shr(i).is_some_and(|v| v & 1 == 1)
+    }
+
+    // any number of bits, the value is reduced mod P
+    pub fn from_bits_le(bits: &[bool]) -> Self {
+        let two = Self::new(2);
+        bits.iter().rev().fold(Self::zero(), |acm, &bit| {
+            acm * two + Self::new(u32::from(bit))
+        })
+    }
+
+    // interprets bytes as a big-endian integer of any length and reduces it mod P
+    pub fn from_bytes_wide(bytes: &[u8]) -> Self {
+        let base = Self::new(256);
+        bytes
+            .iter()
+            .fold(Self::zero(), |acm, &b| acm * base + Self::new(u32::from(b)))
+    }
+
+    // transcript encoding: the reduced value as 4 little-endian bytes, the same width for every P
+    pub fn to_repr(self) -> [u8; 4] {
+        self.val.to_le_bytes()
+    }
+
+    // only accepts canonical encodings, anything >= P is rejected instead of reduced
+    pub fn from_repr(repr: [u8; 4]) -> Option<Self> {
+        Self::try_new(u32::from_le_bytes(repr)).ok()
+    }
+
+    // COORD_BYTES little-endian bytes
+    pub fn to_bytes(self) -> Vec<u8> {
+        self.val.to_le_bytes()[..Self::COORD_BYTES].to_vec()
+    }
+
+    // exactly COORD_BYTES little-endian bytes holding a value < P
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ZookError> {
+        if bytes.len() != Self::COORD_BYTES {
+            return Err(ZookError::LengthMismatch);
+        }
+        let mut repr = [0; 4];
+        repr[..bytes.len()].copy_from_slice(bytes);
+        Self::from_repr(repr).ok_or(ZookError::InvalidEncoding)
+    }
+
+    // same failures as try_inverse, the Div operator panics on them instead
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ZookError> {
+        Ok(self * rhs.try_inverse()?)
+    }
+
+    pub fn inverse(self) -> Option<Self> {
+        self.try_inverse().ok()
+    }
+
+    // NoMultiplicativeInverse can only happen for composite P
+    pub fn try_inverse(self) -> Result<Self, ZookError> {
+        let val = NonZeroU32::new(self.val).ok_or(ZookError::DivisionByZero)?;
+        let inv = multiplicative_inverse(val, NonZeroU32::new(P).unwrap())?;
+        Ok(Self { val: inv.get() })
+    }
+
+    // Fermat inverse self^(P - 2), avoids the data-dependent branches of extended Euclid
+    // but is only correct for prime P
+    pub fn inverse_pow(self) -> Option<Self> {
+        if self.val == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+
+    // inverse for nonzero elements, zero for zero
+    pub fn inv_or_zero(self) -> Self {
+        self.inverse().unwrap_or(Self::zero())
+    }
+
+    // self / rhs, except that dividing by zero gives zero instead of panicking
+    pub fn div_or_zero(self, rhs: Self) -> Self {
+        if rhs.val == 0 {
+            Self::zero()
+        } else {
+            self / rhs
+        }
+    }
+
+    // like pow but treats 0^0 as undefined, pow itself keeps the 0^0 == 1 convention
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        if self.val == 0 && exp == 0 {
+            None
+        } else {
+            Some(self.pow(exp))
+        }
+    }
+
+    // the exponent is an integer, not a field element, so it is never reduced mod P
+    pub fn pow(self, exp: u32) -> Self {
+        self.pow_u64(u64::from(exp))
+    }
+
+    pub fn pow_u64(self, exp: u64) -> Self {
         Self {
-            val: modulus_exp(self.val, rhs.val, NonZeroU32::new(P).unwrap()),
+            val: modulus_exp(self.val, exp, NonZeroU32::new(P).unwrap()),
+        }
+    }
+
+    // self^((P - 1) / n), None unless n divides P - 1
+    pub fn pow_cofactor(self, n: u32) -> Option<Self> {
+        if n == 0 || !(P - 1).is_multiple_of(n) {
+            return None;
+        }
+        Some(self.pow((P - 1) / n))
+    }
+
+    // Euler's criterion, 1 for nonzero squares, -1 for non-squares and 0 for zero
+    pub fn legendre(self) -> i8 {
+        if self.val == 0 {
+            0
+        } else if P == 2 {
+            // 2 doesn't divide P - 1 = 1, but the only nonzero element 1 is a square
+            1
+        } else if self.pow_cofactor(2).is_some_and(|c| c.val == 1) {
+            1
+        } else {
+            -1
+        }
+    }
+
+    // smallest element with legendre symbol -1
+    pub fn quadratic_non_residue() -> Self {
+        if P == 2 {
+            panic!("F_2 has no quadratic non-residue")
+        }
+        (2..P)
+            .map(Self::new)
+            .find(|a| a.legendre() == -1)
+            .expect("no quadratic non-residue, P is not an odd prime")
+    }
+
+    // zero and quadratic residues, sqrt returns Some exactly when this is true
+    pub fn is_square(self) -> bool {
+        self.legendre() != -1
+    }
+
+    // an x with x^n == self, n = 0 has no meaningful root and gives None
+    // gcd(n, P - 1) == 1: the unique root self^(n^-1 mod (P - 1))
+    // otherwise: None unless self^((P - 1) / gcd) == 1, then some root is found by linear
+    // search, which is only practical for small P
+    pub fn nth_root(self, n: u32) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        if self.val == 0 || P == 2 {
+            return Some(self);
+        }
+
+        let order = NonZeroU32::new(P - 1).unwrap();
+        match multiplicative_inverse(NonZeroU32::new(n).unwrap(), order) {
+            Ok(inv) => Some(self.pow(inv.get())),
+            Err(_) => {
+                if self.pow((P - 1) / gcd(n, P - 1)).val != 1 {
+                    return None;
+                }
+                (1..P).map(Self::new).find(|x| x.pow(n) == self)
+            }
+        }
+    }
+
+    // (r, -r), which coincide for zero
+    pub fn sqrt_both(self) -> Option<(Self, Self)> {
+        self.sqrt().map(|r| (r, -r))
+    }
+
+    // Tonelli-Shanks
+    pub fn sqrt(self) -> Option<Self> {
+        if self.val == 0 || P == 2 {
+            return Some(self);
+        }
+        if !self.is_square() {
+            return None;
+        }
+
+        // P - 1 = q * 2^s with q odd
+        let s = (P - 1).trailing_zeros();
+        let q = (P - 1) >> s;
+
+        let mut m = s;
+        let mut c = Self::quadratic_non_residue().pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.div_ceil(2));
+
+        while t.val != 1 {
+            // least i with t^(2^i) == 1
+            let mut i = 0;
+            let mut t2 = t;
+            while t2.val != 1 {
+                t2 = t2 * t2;
+                i += 1;
+            }
+
+            let b = c.pow2k(m - i - 1);
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+
+        Some(r)
+    }
+
+    // smallest k > 0 with self^k == 1, None when self^(P - 1) != 1 (zero, or a non-unit
+    // of composite P) since the order then isn't a divisor of P - 1
+    pub fn multiplicative_order(self) -> Option<u32> {
+        if self.pow(P - 1).val != 1 {
+            return None;
+        }
+        let mut order = P - 1;
+        for q in prime_factors(P - 1) {
+            while order.is_multiple_of(q) && self.pow(order / q).val == 1 {
+                order /= q;
+            }
+        }
+        Some(order)
+    }
+
+    // when self^(P - 1) == 1 (every nonzero element for prime P) the order divides P - 1, so
+    // the exponent is reduced mod P - 1 first. otherwise (zero, non-units of composite P) the
+    // order is unknown and the exponent is used as is
+    pub fn pow_reduced(self, exp: u64) -> Self {
+        if P > 1 && self.pow(P - 1).val == 1 {
+            self.pow_u64(exp % u64::from(P - 1))
+        } else {
+            self.pow_u64(exp)
+        }
+    }
+
+    // [1, self, self^2, ..., self^(n - 1)]
+    pub fn powers(self, n: usize) -> Vec<Self> {
+        let mut powers = Vec::with_capacity(n);
+        let mut cur = Self::one();
+        for _ in 0..n {
+            powers.push(cur);
+            cur *= self;
+        }
+        powers
+    }
+
+    // 1 + r + r^2 + ... + r^(n - 1) in closed form
+    pub fn geometric_sum(r: Self, n: u32) -> Self {
+        if r == Self::one() {
+            Self::new(n)
+        } else {
+            (r.pow(n) - Self::one()) / (r - Self::one())
+        }
+    }
+
+    // fixed addition chains for common S-box exponents
+    pub fn cube(self) -> Self {
+        self * self * self
+    }
+
+    pub fn pow5(self) -> Self {
+        let x2 = self * self;
+        x2 * x2 * self
+    }
+
+    pub fn pow7(self) -> Self {
+        let x2 = self * self;
+        let x3 = x2 * self;
+        x3 * x3 * self
+    }
+
+    // self^(2^k) by squaring k times
+    pub fn pow2k(self, k: u32) -> Self {
+        (0..k).fold(self, |acm, _| acm * acm)
+    }
+
+    // sliding window exponentiation, precomputes self^1, self^3, ..., self^(2^window - 1)
+    pub fn pow_windowed(self, exp: u64, window: usize) -> Self {
+        if !(1..=16).contains(&window) {
+            panic!("window size must be between 1 and 16")
+        }
+
+        let square = self * self;
+        let mut odd_powers = vec![self];
+        for i in 1..(1 << (window - 1)) {
+            odd_powers.push(odd_powers[i - 1] * square);
+        }
+
+        let window = window as u32;
+        let mut acm = Self::one();
+        let mut i = u64::BITS - exp.leading_zeros();
+
+        while i > 0 {
+            if (exp >> (i - 1)) & 1 == 0 {
+                acm = acm * acm;
+                i -= 1;
+            } else {
+                // take the longest run of at most `window` bits ending in a set bit
+                let mut j = i.saturating_sub(window);
+                while (exp >> j) & 1 == 0 {
+                    j += 1;
+                }
+
+                for _ in j..i {
+                    acm = acm * acm;
+                }
+
+                let digit = (exp >> j) & ((1 << (i - j)) - 1);
+                acm *= odd_powers[(digit >> 1) as usize];
+                i = j;
+            }
         }
+
+        acm
+    }
+}
+
+/// Reduces `val` mod `P`, like `FieldElement::new`. Use `FieldElement::try_new` to reject
+/// out-of-range values instead.
+impl<const P: u32> From<u32> for FieldElement<P> {
+    fn from(val: u32) -> Self {
+        FieldElement::new(val)
+    }
+}
+
+impl<const P: u32> Field for FieldElement<P> {
+    fn zero() -> Self {
+        FieldElement::zero()
+    }
+
+    fn one() -> Self {
+        FieldElement::one()
+    }
+
+    fn inverse(self) -> Option<Self> {
+        FieldElement::inverse(self)
+    }
+
+    fn pow(self, e: u64) -> Self {
+        self.pow_u64(e)
     }
 }
 
@@ -63,15 +557,9 @@ impl<const P: u32> Div for FieldElement<P> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        Self {
-            val: match modulus_div(
-                self.val,
-                NonZeroU32::new(rhs.val).expect("division by 0"),
-                NonZeroU32::new(P).unwrap(),
-            ) {
-                Ok(new_val) => new_val,
-                Err(ModularArithmeticError::NoMultiplicativeInverse) => panic!("gcd(a,n) != 1"),
-            },
+        match self.checked_div(rhs) {
+            Ok(quotient) => quotient,
+            Err(err) => panic!("{err}"),
         }
     }
 }
@@ -81,49 +569,168 @@ impl<const P: u32> Neg for FieldElement<P> {
 
     fn neg(self) -> Self::Output {
         Self {
-            val: modulus_sub(0, self.val, NonZeroU32::new(P).unwrap()),
+            val: additive_inverse(self.val, NonZeroU32::new(P).unwrap()),
         }
     }
 }
 
-fn modulus_add(a: u32, b: u32, n: NonZeroU32) -> u32 {
-    u32::try_from((u64::from(a) + u64::from(b)).rem_euclid(u64::from(n.get())))
-        .expect("unexpected overflow in modulus addition")
+impl<const P: u32> AddAssign for FieldElement<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
 }
 
-fn modulus_sub(a: u32, b: u32, n: NonZeroU32) -> u32 {
-    if a > b {
-        (a - b).rem_euclid(n.get())
-    } else {
-        let b_inverse = additive_inverse(b, n);
-        modulus_add(a, b_inverse, n)
+impl<const P: u32> AddAssign<&FieldElement<P>> for FieldElement<P> {
+    fn add_assign(&mut self, rhs: &Self) {
+        *self = *self + *rhs;
+    }
+}
+
+impl<const P: u32> SubAssign for FieldElement<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u32> SubAssign<&FieldElement<P>> for FieldElement<P> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = *self - *rhs;
+    }
+}
+
+impl<const P: u32> MulAssign for FieldElement<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u32> MulAssign<&FieldElement<P>> for FieldElement<P> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = *self * *rhs;
+    }
+}
+
+impl<const P: u32> DivAssign for FieldElement<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u32> DivAssign<&FieldElement<P>> for FieldElement<P> {
+    fn div_assign(&mut self, rhs: &Self) {
+        *self = *self / *rhs;
+    }
+}
+
+impl<const P: u32> Sum for FieldElement<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acm, x| acm + x)
+    }
+}
+
+impl<'a, const P: u32> Sum<&'a FieldElement<P>> for FieldElement<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+// evaluates little-endian coefficients at x without building a Polynomial
+pub fn horner_eval<const P: u32>(
+    coeffs: &[FieldElement<P>],
+    x: FieldElement<P>,
+) -> FieldElement<P> {
+    coeffs
+        .iter()
+        .rev()
+        .fold(FieldElement::zero(), |acm, &c| acm * x + c)
+}
+
+// compares any two representations, e.g. a FieldElement against a SplitFieldElement
+pub fn eq_canonical<const P: u32>(
+    a: impl Into<FieldElement<P>>,
+    b: impl Into<FieldElement<P>>,
+) -> bool {
+    a.into().canonical() == b.into().canonical()
+}
+
+pub fn inner_product<const P: u32>(
+    a: &[FieldElement<P>],
+    b: &[FieldElement<P>],
+) -> Result<FieldElement<P>, ZookError> {
+    if a.len() != b.len() {
+        return Err(ZookError::LengthMismatch);
+    }
+    Ok(a.iter()
+        .zip(b)
+        .fold(FieldElement::zero(), |acm, (&x, &y)| acm + x * y))
+}
+
+// elementwise product
+pub fn hadamard<const P: u32>(
+    a: &[FieldElement<P>],
+    b: &[FieldElement<P>],
+) -> Result<Vec<FieldElement<P>>, ZookError> {
+    if a.len() != b.len() {
+        return Err(ZookError::LengthMismatch);
     }
+    Ok(a.iter().zip(b).map(|(&x, &y)| x * y).collect())
+}
+
+// Montgomery's trick, inverts every element with a single field inversion. requires all
+// elements to be invertible
+pub(crate) fn batch_inverse<const P: u32>(values: &[FieldElement<P>]) -> Vec<FieldElement<P>> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acm = FieldElement::one();
+    for &v in values {
+        prefix.push(acm);
+        acm *= v;
+    }
+
+    let mut inv = acm
+        .inverse()
+        .expect("batch_inverse of a non-invertible element");
+    let mut inverses = vec![FieldElement::zero(); values.len()];
+    for i in (0..values.len()).rev() {
+        inverses[i] = inv * prefix[i];
+        inv *= values[i];
+    }
+    inverses
+}
+
+// invariant: operands are u32, so sums fit in u64 and products in u128, and both reduce below n
+pub(crate) fn modulus_add(a: u32, b: u32, n: NonZeroU32) -> u32 {
+    let r = (u64::from(a) + u64::from(b)).rem_euclid(u64::from(n.get()));
+    debug_assert!(r < u64::from(n.get()));
+    r as u32
+}
+
+pub(crate) fn modulus_sub(a: u32, b: u32, n: NonZeroU32) -> u32 {
+    reduce_signed(i64::from(a) - i64::from(b), n)
 }
 
-fn modulus_mul(a: u32, b: u32, n: NonZeroU32) -> u32 {
-    u32::try_from((u64::from(a) * u64::from(b)).rem_euclid(u64::from(n.get())))
-        .expect("unexpected overflow in modulus multiplication")
+pub(crate) fn modulus_mul(a: u32, b: u32, n: NonZeroU32) -> u32 {
+    let r = (u128::from(a) * u128::from(b)).rem_euclid(u128::from(n.get()));
+    debug_assert!(r < u128::from(n.get()));
+    r as u32
 }
 
-fn modulus_div(a: u32, b: NonZeroU32, n: NonZeroU32) -> Result<u32, ModularArithmeticError> {
+pub(crate) fn modulus_div(a: u32, b: NonZeroU32, n: NonZeroU32) -> Result<u32, ZookError> {
     Ok(modulus_mul(a, multiplicative_inverse(b, n)?.get(), n))
 }
 
-fn modulus_exp(a: u32, b: u32, n: NonZeroU32) -> u32 {
+fn modulus_exp(a: u32, b: u64, n: NonZeroU32) -> u32 {
     if n.get() == 1 {
         0
-    } else if b == 0 {
-        1
     } else {
-        let mut acm = 0;
-        let mut cur = a;
-        let bits = u32::BITS - b.leading_zeros();
+        let mut acm = 1;
+        let mut cur = a.rem_euclid(n.get());
+        let bits = u64::BITS - b.leading_zeros();
 
         for i in 0..bits {
-            cur = modulus_add(cur, cur, n);
             if (b >> i) & 1 == 1 {
-                acm = modulus_add(acm, cur, n);
+                acm = modulus_mul(acm, cur, n);
             }
+            cur = modulus_mul(cur, cur, n);
         }
 
         acm
@@ -131,19 +738,51 @@ fn modulus_exp(a: u32, b: u32, n: NonZeroU32) -> u32 {
 }
 
 fn additive_inverse(a: u32, n: NonZeroU32) -> u32 {
-    n.get() - a.rem_euclid(n.get())
+    reduce_signed(-i64::from(a), n)
+}
+
+// canonical residue of any value reachable by adding or subtracting two u32s
+fn reduce_signed(v: i64, n: NonZeroU32) -> u32 {
+    v.rem_euclid(i64::from(n.get())) as u32
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// distinct prime factors by trial division
+pub(crate) fn prime_factors(mut n: u32) -> Vec<u32> {
+    let mut factors = vec![];
+    let mut d = 2;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            factors.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
 }
 
 // requires gcd(a, n) == 1
-fn multiplicative_inverse(
-    a: NonZeroU32,
-    n: NonZeroU32,
-) -> Result<NonZeroU32, ModularArithmeticError> {
+fn multiplicative_inverse(a: NonZeroU32, n: NonZeroU32) -> Result<NonZeroU32, ZookError> {
     let a = a.get();
     let n = n.get();
     let mut cur_n = i64::from(n);
     let mut cur_a = i64::from(a.rem_euclid(n));
 
+    if cur_a == 0 {
+        return Err(ZookError::NoMultiplicativeInverse);
+    }
+
     let mut val = 1;
     let mut pre = 0;
     let mut rem = cur_n % cur_a;
@@ -156,10 +795,11 @@ fn multiplicative_inverse(
         cur_a = rem;
 
         rem = cur_n % cur_a;
+    }
 
-        if rem == 0 && cur_a != 1 {
-            return Err(ModularArithmeticError::NoMultiplicativeInverse);
-        }
+    // cur_a is now gcd(a, n)
+    if cur_a != 1 {
+        return Err(ZookError::NoMultiplicativeInverse);
     }
 
     Ok(NonZeroU32::new(
@@ -197,27 +837,240 @@ mod tests {
         assert_eq!(a.val(), 2);
     }
 
-    mod addition {
+    mod conversion {
         use super::super::*;
 
         #[test]
-        fn non_overflowing_addition() {
-            let a = FieldElement::<5>::new(1);
-            let b = FieldElement::<5>::new(3);
+        fn from_reduces() {
+            assert_eq!(FieldElement::<5>::from(3).val(), 3);
+            assert_eq!(FieldElement::<5>::from(12).val(), 2);
+        }
 
-            assert_eq!((a + b).val(), 4);
+        #[test]
+        fn new_unchecked_matches_new_in_range() {
+            for a in 0..5 {
+                assert_eq!(FieldElement::<5>::new_unchecked(a), FieldElement::new(a));
+            }
         }
 
         #[test]
-        fn overflowing_addition() {
-            let a = FieldElement::<5>::new(3);
-            let b = FieldElement::<5>::new(4);
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "new_unchecked value out of range")]
+        fn new_unchecked_asserts_range_in_debug() {
+            let _a = FieldElement::<5>::new_unchecked(5);
+        }
 
-            assert_eq!((a + b).val(), 2);
+        #[test]
+        fn try_new_accepts_canonical_values() {
+            assert_eq!(FieldElement::<5>::try_new(0), Ok(FieldElement::new(0)));
+            assert_eq!(FieldElement::<5>::try_new(4), Ok(FieldElement::new(4)));
         }
 
         #[test]
-        fn it_is_commutative() {
+        fn try_new_rejects_out_of_range_values() {
+            assert_eq!(
+                FieldElement::<5>::try_new(5),
+                Err(ZookError::InvalidEncoding)
+            );
+            assert_eq!(
+                FieldElement::<5>::try_new(u32::MAX),
+                Err(ZookError::InvalidEncoding)
+            );
+        }
+
+        #[test]
+        fn try_new_rejects_zero_modulus() {
+            assert_eq!(FieldElement::<0>::try_new(0), Err(ZookError::ModuloZero));
+        }
+    }
+
+    #[test]
+    fn it_sums_owned_and_borrowed_elements() {
+        let values: Vec<_> = (1..=6).map(FieldElement::<7>::new).collect();
+        let by_ref: FieldElement<7> = values.iter().sum();
+        let by_value: FieldElement<7> = values.into_iter().sum();
+
+        assert_eq!(by_ref, FieldElement::zero());
+        assert_eq!(by_value, by_ref);
+        assert_eq!(
+            [FieldElement::<7>::new(3)].iter().sum::<FieldElement<7>>(),
+            FieldElement::new(3)
+        );
+        assert_eq!(
+            std::iter::empty::<FieldElement<7>>().sum::<FieldElement<7>>(),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn checked_div_reports_failures() {
+        let a = FieldElement::<8>::new(3);
+        assert_eq!(
+            a.checked_div(FieldElement::new(0)),
+            Err(ZookError::DivisionByZero)
+        );
+        assert_eq!(
+            a.checked_div(FieldElement::new(2)),
+            Err(ZookError::NoMultiplicativeInverse)
+        );
+        assert_eq!(a.checked_div(FieldElement::new(3)), Ok(FieldElement::one()));
+    }
+
+    #[test]
+    #[should_panic(expected = "gcd(a,n) != 1")]
+    fn div_panics_on_non_units() {
+        let _q = FieldElement::<8>::new(3) / FieldElement::new(2);
+    }
+
+    #[test]
+    fn assign_operators_match_binary_operators() {
+        for a in 0..7 {
+            for b in 1..7 {
+                let a = FieldElement::<7>::new(a);
+                let b = FieldElement::<7>::new(b);
+
+                let (mut by_value, mut by_ref) = (a, a);
+                by_value += b;
+                by_ref += &b;
+                assert_eq!(by_value, a + b);
+                assert_eq!(by_ref, by_value);
+
+                let (mut by_value, mut by_ref) = (a, a);
+                by_value -= b;
+                by_ref -= &b;
+                assert_eq!(by_value, a - b);
+                assert_eq!(by_ref, by_value);
+
+                let (mut by_value, mut by_ref) = (a, a);
+                by_value *= b;
+                by_ref *= &b;
+                assert_eq!(by_value, a * b);
+                assert_eq!(by_ref, by_value);
+
+                let (mut by_value, mut by_ref) = (a, a);
+                by_value /= b;
+                by_ref /= &b;
+                assert_eq!(by_value, a / b);
+                assert_eq!(by_ref, by_value);
+            }
+        }
+    }
+
+    #[test]
+    fn named_arithmetic_matches_operators() {
+        for a in 0..7 {
+            for b in 0..7 {
+                let a = FieldElement::<7>::new(a);
+                let b = FieldElement::<7>::new(b);
+
+                assert_eq!(a.add_mod(b), a + b);
+                assert_eq!(a.sub_mod(b), a - b);
+                assert_eq!(a.mul_mod(b), a * b);
+            }
+        }
+    }
+
+    #[test]
+    fn from_i64_wraps_negative_values() {
+        assert_eq!(FieldElement::<7>::from_i64(-1), FieldElement::new(6));
+        assert_eq!(FieldElement::<7>::from_i64(15), FieldElement::new(1));
+        // 2^63 = (2^3)^21 = 1 mod 7
+        assert_eq!(FieldElement::<7>::from_i64(i64::MIN), FieldElement::new(6));
+        assert_eq!(
+            FieldElement::<4294967291>::from_i64(-4294967292),
+            FieldElement::new(4294967290)
+        );
+    }
+
+    #[test]
+    fn mul_i64_matches_signed_multiplication() {
+        for a in 0..7 {
+            let a = FieldElement::<7>::new(a);
+            assert_eq!(a.mul_i64(-2), -(a + a));
+            for k in -20..=20 {
+                assert_eq!(a.mul_i64(k), a * FieldElement::from_i64(k));
+            }
+        }
+
+        let a = FieldElement::<4294967291>::new(123456789);
+        for k in [i64::MIN, i64::MIN + 1, -4294967291, 4294967293, i64::MAX] {
+            assert_eq!(a.mul_i64(k), a * FieldElement::from_i64(k));
+        }
+    }
+
+    #[test]
+    fn mul_by_u32_matches_multiplication() {
+        for a in 0..7 {
+            let a = FieldElement::<7>::new(a);
+            for k in 0..=20 {
+                assert_eq!(a.mul_by_u32(k), a * FieldElement::new(k));
+            }
+        }
+
+        let a = FieldElement::<4294967291>::new(4294967290);
+        for k in [0, 3, 8, 9, 4294967291, 4294967295] {
+            assert_eq!(a.mul_by_u32(k), a * FieldElement::new(k));
+        }
+    }
+
+    #[test]
+    fn canonical_is_the_identity() {
+        for a in 0..7 {
+            let a = FieldElement::<7>::new(a);
+            assert_eq!(a.canonical(), a);
+            assert_eq!(a.canonical().canonical(), a.canonical());
+            assert!(eq_canonical(a, a));
+        }
+        assert!(!eq_canonical(
+            FieldElement::<7>::new(1),
+            FieldElement::<7>::new(2)
+        ));
+    }
+
+    #[test]
+    fn eq_u32_reduces_the_constant() {
+        let a = FieldElement::<7>::new(3);
+        assert!(a.eq_u32(3));
+        assert!(a.eq_u32(10));
+        assert!(!a.eq_u32(4));
+        assert!(FieldElement::<7>::zero().eq_u32(7));
+        assert!(FieldElement::<4294967291>::new(4).eq_u32(u32::MAX));
+    }
+
+    #[test]
+    fn it_widens_to_canonical_value() {
+        let a = FieldElement::<5>::new(9);
+        assert_eq!(a.to_u64(), u64::from(a.val()));
+        assert_eq!(a.to_usize(), a.val() as usize);
+    }
+
+    #[test]
+    fn it_reports_prime_field_metadata() {
+        assert_eq!(FieldElement::<7>::characteristic(), 7);
+        assert_eq!(FieldElement::<7>::extension_degree(), 1);
+    }
+
+    mod addition {
+        use super::super::*;
+
+        #[test]
+        fn non_overflowing_addition() {
+            let a = FieldElement::<5>::new(1);
+            let b = FieldElement::<5>::new(3);
+
+            assert_eq!((a + b).val(), 4);
+        }
+
+        #[test]
+        fn overflowing_addition() {
+            let a = FieldElement::<5>::new(3);
+            let b = FieldElement::<5>::new(4);
+
+            assert_eq!((a + b).val(), 2);
+        }
+
+        #[test]
+        fn it_is_commutative() {
             let a = FieldElement::<5>::new(3);
             let b = FieldElement::<5>::new(4);
 
@@ -242,6 +1095,978 @@ mod tests {
         }
     }
 
+    mod large_modulus {
+        use super::super::*;
+
+        const P: u32 = 4294967291;
+
+        #[test]
+        fn maximal_products_do_not_overflow() {
+            let n = NonZeroU32::new(P).unwrap();
+
+            for a in P - 4..P {
+                for b in P - 4..P {
+                    let expected = (u128::from(a) * u128::from(b)) % u128::from(P);
+                    assert_eq!(u128::from(modulus_mul(a, b, n)), expected);
+                }
+            }
+        }
+
+        #[test]
+        fn it_squares_p_minus_one() {
+            // (P - 1)^2 = (-1)^2 = 1
+            let a = FieldElement::<P>::new(P - 1);
+            assert_eq!((a * a).val(), 1);
+        }
+
+        #[test]
+        fn maximal_sums_do_not_overflow() {
+            let a = FieldElement::<P>::new(P - 1);
+            assert_eq!((a + a).val(), P - 2);
+        }
+    }
+
+    mod subtraction {
+        use super::super::*;
+
+        #[test]
+        fn it_equals_addition_of_additive_inverse() {
+            let n = NonZeroU32::new(13).unwrap();
+
+            for a in 0..13 {
+                for b in 0..13 {
+                    assert_eq!(
+                        modulus_sub(a, b, n),
+                        modulus_add(a, additive_inverse(b, n), n)
+                    );
+                }
+            }
+        }
+
+        // the two-branch implementation that predates reduce_signed
+        fn branching_sub(a: u32, b: u32, n: NonZeroU32) -> u32 {
+            if a > b {
+                (a - b).rem_euclid(n.get())
+            } else {
+                let b_inverse = match b.rem_euclid(n.get()) {
+                    0 => 0,
+                    r => n.get() - r,
+                };
+                modulus_add(a, b_inverse, n)
+            }
+        }
+
+        #[test]
+        fn it_matches_branching_implementation() {
+            for n in [1, 2, 3, 13, 16] {
+                let n = NonZeroU32::new(n).unwrap();
+                for a in 0..n.get() {
+                    for b in 0..n.get() {
+                        assert_eq!(modulus_sub(a, b, n), branching_sub(a, b, n));
+                    }
+                }
+            }
+
+            let n = NonZeroU32::new(4294967291).unwrap();
+            for (a, b) in [
+                (0, 4294967290),
+                (4294967290, 0),
+                (1, 2),
+                (4294967290, 4294967289),
+            ] {
+                assert_eq!(modulus_sub(a, b, n), branching_sub(a, b, n));
+            }
+        }
+
+        #[test]
+        fn negation_matches_subtraction_from_zero() {
+            for a in 0..13 {
+                let a = FieldElement::<13>::new(a);
+                assert_eq!(-a, FieldElement::zero() - a);
+            }
+        }
+
+        #[test]
+        fn it_matches_integer_subtraction() {
+            let n = NonZeroU32::new(13).unwrap();
+
+            for a in 0..13 {
+                for b in 0..13 {
+                    let expected = (i64::from(a) - i64::from(b)).rem_euclid(13);
+                    assert_eq!(i64::from(modulus_sub(a, b, n)), expected);
+                }
+            }
+        }
+    }
+
+    mod exponentiation {
+        use super::super::*;
+
+        // deterministic stand-in for random exponents
+        fn exponents() -> impl Iterator<Item = u64> {
+            let mut state = 0x2545_f491_4f6c_dd1d_u64;
+            (0..64).map(move |_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                state
+            })
+        }
+
+        #[test]
+        fn multiplicative_order_is_the_smallest_period() {
+            for a in 1..97 {
+                let a = FieldElement::<97>::new(a);
+                let expected = (1..97).find(|&k| a.pow(k).val() == 1);
+                assert_eq!(a.multiplicative_order(), expected);
+            }
+            assert_eq!(FieldElement::<97>::zero().multiplicative_order(), None);
+            assert_eq!(
+                FieldElement::<4294967291>::new(4294967290).multiplicative_order(),
+                Some(2)
+            );
+        }
+
+        #[test]
+        fn multiplicative_order_of_non_units_is_unknown() {
+            assert_eq!(FieldElement::<8>::new(2).multiplicative_order(), None);
+        }
+
+        #[test]
+        fn pow_reduced_leaves_non_units_of_composite_p_unreduced() {
+            assert_eq!(
+                FieldElement::<8>::new(2).pow_reduced(8),
+                FieldElement::zero()
+            );
+            assert_eq!(
+                FieldElement::<9>::new(3).pow_reduced(8),
+                FieldElement::zero()
+            );
+            for a in 0..9 {
+                let a = FieldElement::<9>::new(a);
+                for e in exponents().chain([0, 1, 8, 9]) {
+                    assert_eq!(a.pow_reduced(e), a.pow_u64(e));
+                }
+            }
+        }
+
+        #[test]
+        fn pow_reduced_agrees_with_pow_u64() {
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                for e in exponents().chain([0, 1, 96, 97]) {
+                    assert_eq!(a.pow_reduced(e), a.pow_u64(e));
+                }
+            }
+            let zero = FieldElement::<97>::zero();
+            assert_eq!(zero.pow_reduced(0), FieldElement::one());
+            assert_eq!(zero.pow_reduced(u64::MAX), zero);
+            assert_eq!(
+                FieldElement::<97>::one().pow_reduced(u64::MAX),
+                FieldElement::one()
+            );
+            let a = FieldElement::<4294967291>::new(3);
+            assert_eq!(a.pow_reduced(u64::MAX), a.pow_u64(u64::MAX));
+        }
+
+        #[test]
+        fn checked_pow_leaves_zero_to_the_zero_undefined() {
+            let zero = FieldElement::<7>::zero();
+            assert_eq!(zero.checked_pow(0), None);
+            assert_eq!(zero.pow(0), FieldElement::one());
+            for k in 1..10 {
+                assert_eq!(zero.checked_pow(k), Some(zero));
+            }
+            for a in 1..7 {
+                let a = FieldElement::<7>::new(a);
+                assert_eq!(a.checked_pow(0), Some(FieldElement::one()));
+                assert_eq!(a.checked_pow(5), Some(a.pow(5)));
+            }
+        }
+
+        #[test]
+        fn pow_matches_repeated_multiplication() {
+            let a = FieldElement::<97>::new(5);
+            let mut expected = FieldElement::<97>::one();
+
+            for e in 0..97 {
+                assert_eq!(a.pow(e).val(), expected.val());
+                expected *= a;
+            }
+        }
+
+        #[test]
+        fn pow_is_exponentiation_not_multiplication() {
+            // 2^3 = 8 = 1 mod 7
+            assert_eq!(FieldElement::<7>::new(2).pow(3).val(), 1);
+        }
+
+        #[test]
+        fn pow_does_not_reduce_exponent_mod_p() {
+            // 3^7 = 3 mod 7 by Fermat, while 3^(7 mod 7) would be 1
+            assert_eq!(FieldElement::<7>::new(3).pow(7).val(), 3);
+            assert_eq!(FieldElement::<7>::new(3).pow(9).val(), 3u32.pow(9) % 7);
+        }
+
+        #[test]
+        fn pow_u64_handles_exponents_beyond_u32() {
+            let a = FieldElement::<13>::new(2);
+            // the order of 2 mod 13 is 12, so only e mod 12 matters
+            for e in [
+                u64::from(u32::MAX) + 1,
+                u64::from(u32::MAX) * 7 + 5,
+                u64::MAX,
+            ] {
+                let expected = (0..e % 12).fold(FieldElement::one(), |acm, _| acm * a);
+                assert_eq!(a.pow_u64(e), expected);
+            }
+        }
+
+        #[test]
+        fn pow_u64_matches_repeated_multiplication() {
+            let a = FieldElement::<13>::new(6);
+            let mut expected = FieldElement::<13>::one();
+            for e in 0..50 {
+                assert_eq!(a.pow_u64(e), expected);
+                assert_eq!(a.pow(e as u32), expected);
+                expected *= a;
+            }
+        }
+
+        #[test]
+        fn powers_match_pow() {
+            let a = FieldElement::<97>::new(13);
+            let powers = a.powers(20);
+
+            assert_eq!(powers.len(), 20);
+            for (i, p) in powers.into_iter().enumerate() {
+                assert_eq!(p, a.pow(i as u32));
+            }
+            assert!(a.powers(0).is_empty());
+        }
+
+        #[test]
+        fn geometric_sum_matches_naive_sum() {
+            for r in [0, 1, 2, 5, 96] {
+                let r = FieldElement::<97>::new(r);
+                for n in [0, 1, 2, 10, 200] {
+                    let naive = r
+                        .powers(n as usize)
+                        .into_iter()
+                        .fold(FieldElement::zero(), |acm, p| acm + p);
+                    assert_eq!(FieldElement::geometric_sum(r, n), naive);
+                }
+            }
+        }
+
+        #[test]
+        fn fixed_chains_match_pow() {
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                assert_eq!(a.cube(), a.pow(3));
+                assert_eq!(a.pow5(), a.pow(5));
+                assert_eq!(a.pow7(), a.pow(7));
+            }
+        }
+
+        #[test]
+        fn pow2k_matches_pow() {
+            let a = FieldElement::<97>::new(10);
+
+            for k in 0..5 {
+                assert_eq!(a.pow2k(k), a.pow(2u32.pow(k)));
+            }
+        }
+
+        #[test]
+        fn pow_windowed_matches_pow() {
+            let a = FieldElement::<4294967291>::new(123456789);
+
+            for e in exponents() {
+                let e = e % 4294967291;
+                for window in 1..=6 {
+                    assert_eq!(a.pow_windowed(e, window).val(), a.pow(e as u32).val());
+                }
+            }
+        }
+
+        #[test]
+        fn pow_windowed_agrees_across_window_sizes() {
+            let a = FieldElement::<65537>::new(3);
+
+            for e in exponents() {
+                let expected = a.pow_windowed(e, 1).val();
+                for window in 2..=8 {
+                    assert_eq!(a.pow_windowed(e, window).val(), expected);
+                }
+            }
+        }
+
+        #[test]
+        fn pow_windowed_of_zero_exponent_is_one() {
+            assert_eq!(FieldElement::<7>::new(0).pow_windowed(0, 4).val(), 1);
+            assert_eq!(FieldElement::<7>::new(3).pow_windowed(0, 4).val(), 1);
+        }
+
+        #[test]
+        #[should_panic(expected = "window size must be between 1 and 16")]
+        fn pow_windowed_panics_on_zero_window() {
+            let _a = FieldElement::<7>::new(3).pow_windowed(5, 0);
+        }
+    }
+
+    mod negation {
+        use super::super::*;
+
+        #[test]
+        fn additive_inverse_of_zero_is_zero() {
+            for n in [1, 2, 7, 4294967291] {
+                let n = NonZeroU32::new(n).unwrap();
+                assert_eq!(additive_inverse(0, n), 0);
+                assert_eq!(additive_inverse(n.get(), n), 0);
+            }
+        }
+
+        #[test]
+        fn additive_inverse_is_canonical() {
+            let n = NonZeroU32::new(7).unwrap();
+            for a in 0..14 {
+                assert!(additive_inverse(a, n) < 7);
+            }
+        }
+
+        #[test]
+        fn negated_zero_is_zero() {
+            assert_eq!(-FieldElement::<7>::zero(), FieldElement::<7>::zero());
+        }
+
+        #[test]
+        fn negate_twice_restores_original() {
+            for a in 0..7 {
+                let original = FieldElement::<7>::new(a);
+                let mut b = original;
+                b.negate();
+                assert_eq!(b, -original);
+                b.negate();
+                assert_eq!(b, original);
+            }
+        }
+
+        #[test]
+        fn negate_of_zero_stays_zero() {
+            let mut a = FieldElement::<7>::zero();
+            a.negate();
+            assert_eq!(a, FieldElement::zero());
+            a.neg_assign();
+            assert_eq!(a, FieldElement::zero());
+        }
+
+        #[test]
+        fn it_adds_to_zero() {
+            for a in 0..7 {
+                let a = FieldElement::<7>::new(a);
+                assert_eq!((a + -a).val(), 0);
+            }
+        }
+    }
+
+    mod residues {
+        use super::super::*;
+
+        #[test]
+        fn pow_cofactor_two_is_the_legendre_symbol() {
+            for a in 1..13 {
+                let a = FieldElement::<13>::new(a);
+                let expected = if a.legendre() == 1 { 1 } else { 12 };
+                assert_eq!(a.pow_cofactor(2), Some(FieldElement::new(expected)));
+            }
+        }
+
+        #[test]
+        fn pow_cofactor_requires_a_divisor_of_p_minus_one() {
+            let a = FieldElement::<13>::new(2);
+            assert_eq!(a.pow_cofactor(5), None);
+            assert_eq!(a.pow_cofactor(0), None);
+            assert_eq!(a.pow_cofactor(12), Some(a));
+            assert_eq!(a.pow_cofactor(1), Some(FieldElement::one()));
+            assert_eq!(FieldElement::<2>::one().pow_cofactor(2), None);
+        }
+
+        #[test]
+        fn legendre_of_squares_is_one() {
+            for a in 1..13 {
+                let a = FieldElement::<13>::new(a);
+                assert_eq!((a * a).legendre(), 1);
+            }
+            assert_eq!(FieldElement::<13>::zero().legendre(), 0);
+        }
+
+        #[test]
+        fn non_residue_is_not_a_square() {
+            let n = FieldElement::<13>::quadratic_non_residue();
+
+            assert_eq!(n.legendre(), -1);
+            for a in 0..13 {
+                let a = FieldElement::<13>::new(a);
+                assert_ne!(a * a, n);
+            }
+        }
+
+        #[test]
+        fn non_residue_is_the_smallest() {
+            // squares mod 7 are 1, 2, 4
+            assert_eq!(FieldElement::<7>::quadratic_non_residue().val(), 3);
+            // squares mod 17 are 1, 2, 4, 8, 9, 13, 15, 16
+            assert_eq!(FieldElement::<17>::quadratic_non_residue().val(), 3);
+        }
+
+        #[test]
+        fn squares_are_squares() {
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                assert!((a * a).is_square());
+            }
+        }
+
+        #[test]
+        fn is_square_agrees_with_sqrt() {
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                assert_eq!(a.is_square(), a.sqrt().is_some());
+            }
+            assert!(!FieldElement::<97>::quadratic_non_residue().is_square());
+        }
+
+        #[test]
+        fn sqrt_squares_back() {
+            // 97 - 1 = 3 * 2^5 exercises the full Tonelli-Shanks loop
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                if let Some(r) = a.sqrt() {
+                    assert_eq!(r * r, a);
+                }
+            }
+            for a in 0..7 {
+                let a = FieldElement::<7>::new(a);
+                if let Some(r) = a.sqrt() {
+                    assert_eq!(r * r, a);
+                }
+            }
+        }
+
+        #[test]
+        fn nth_root_inverts_coprime_powers() {
+            // gcd(5, 96) = 1 makes x^5 a permutation of F_97
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                assert_eq!(a.pow(5).nth_root(5), Some(a));
+            }
+            assert_eq!(
+                FieldElement::<97>::new(3).nth_root(1),
+                Some(FieldElement::new(3))
+            );
+        }
+
+        #[test]
+        fn nth_root_finds_a_root_of_non_coprime_powers() {
+            // gcd(3, 96) = 3, so cubing is three-to-one
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                let r = a.pow(3).nth_root(3).unwrap();
+                assert_eq!(r.pow(3), a.pow(3));
+            }
+            let cubes: Vec<_> = (0..97).map(|a| FieldElement::<97>::new(a).pow(3)).collect();
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                assert_eq!(a.nth_root(3).is_some(), cubes.contains(&a));
+            }
+        }
+
+        #[test]
+        fn nth_root_rejects_non_powers_without_searching() {
+            let qnr = FieldElement::<97>::quadratic_non_residue();
+            assert_eq!(qnr.nth_root(2), None);
+            assert_eq!(qnr.nth_root(6), None);
+            // a linear search over this field would not finish
+            let qnr = FieldElement::<4294967291>::quadratic_non_residue();
+            assert_eq!(qnr.nth_root(2), None);
+        }
+
+        #[test]
+        fn nth_root_of_zeroth_power_is_none() {
+            assert_eq!(FieldElement::<97>::one().nth_root(0), None);
+        }
+
+        #[test]
+        fn sqrt_both_returns_distinct_roots() {
+            for a in 1..97 {
+                let a = FieldElement::<97>::new(a);
+                match a.sqrt_both() {
+                    Some((r, s)) => {
+                        assert_eq!(r * r, a);
+                        assert_eq!(s * s, a);
+                        assert_ne!(r, s);
+                    }
+                    None => assert!(!a.is_square()),
+                }
+            }
+            let zero = FieldElement::<97>::zero();
+            assert_eq!(zero.sqrt_both(), Some((zero, zero)));
+        }
+
+        #[test]
+        #[should_panic(expected = "F_2 has no quadratic non-residue")]
+        fn it_panics_for_p_2() {
+            let _n = FieldElement::<2>::quadratic_non_residue();
+        }
+    }
+
+    mod inversion {
+        use super::super::*;
+
+        #[test]
+        fn zero_has_no_inverse() {
+            assert!(FieldElement::<7>::zero().inverse().is_none());
+        }
+
+        fn assert_all_inverses<const P: u32>() {
+            assert!(FieldElement::<P>::zero().inverse().is_none());
+
+            for a in 1..P {
+                let a = FieldElement::<P>::new(a);
+                assert_eq!(a * a.inverse().unwrap(), FieldElement::one());
+            }
+        }
+
+        #[test]
+        fn every_nonzero_element_is_invertible_across_primes() {
+            assert_all_inverses::<2>();
+            assert_all_inverses::<3>();
+            assert_all_inverses::<5>();
+            assert_all_inverses::<7>();
+            assert_all_inverses::<13>();
+            assert_all_inverses::<97>();
+            assert_all_inverses::<251>();
+        }
+
+        #[test]
+        fn try_inverse_of_zero_is_division_by_zero() {
+            assert_eq!(
+                FieldElement::<7>::zero().try_inverse(),
+                Err(ZookError::DivisionByZero)
+            );
+            assert_eq!(
+                FieldElement::<8>::zero().try_inverse(),
+                Err(ZookError::DivisionByZero)
+            );
+        }
+
+        #[test]
+        fn try_inverse_rejects_non_units_of_composite_modulus() {
+            for a in [2, 4, 6] {
+                assert_eq!(
+                    FieldElement::<8>::new(a).try_inverse(),
+                    Err(ZookError::NoMultiplicativeInverse)
+                );
+            }
+            // gcd(2, 4) = 2 is detected before the first Euclid step
+            assert_eq!(FieldElement::<4>::new(2).inverse(), None);
+        }
+
+        #[test]
+        fn try_inverse_inverts_units() {
+            for a in [1, 3, 5, 7] {
+                let a = FieldElement::<8>::new(a);
+                assert_eq!(a * a.try_inverse().unwrap(), FieldElement::one());
+            }
+            let a = FieldElement::<7>::new(3);
+            assert_eq!(a.try_inverse(), Ok(FieldElement::new(5)));
+        }
+
+        #[test]
+        fn inverse_pow_agrees_with_inverse() {
+            assert_eq!(FieldElement::<97>::zero().inverse_pow(), None);
+            for a in 1..97 {
+                let a = FieldElement::<97>::new(a);
+                assert_eq!(a.inverse_pow(), a.inverse());
+            }
+        }
+
+        #[test]
+        fn inv_or_zero_inverts_nonzero_elements() {
+            for a in 1..7 {
+                let a = FieldElement::<7>::new(a);
+                assert_eq!((a * a.inv_or_zero()).val(), 1);
+            }
+        }
+
+        #[test]
+        fn div_or_zero_matches_division() {
+            for a in 0..7 {
+                let a = FieldElement::<7>::new(a);
+                assert_eq!(a.div_or_zero(FieldElement::zero()), FieldElement::zero());
+                for b in 1..7 {
+                    let b = FieldElement::new(b);
+                    assert_eq!(a.div_or_zero(b), a / b);
+                }
+            }
+        }
+
+        #[test]
+        fn inv_or_zero_maps_zero_to_zero() {
+            assert_eq!(FieldElement::<7>::zero().inv_or_zero().val(), 0);
+        }
+
+        fn assert_division_inverts_multiplication<const P: u32>() {
+            for a in 0..P {
+                for b in 1..P {
+                    let a = FieldElement::<P>::new(a);
+                    let b = FieldElement::<P>::new(b);
+                    assert_eq!((a / b) * b, a);
+                }
+            }
+        }
+
+        // for prime P every nonzero divisor is a unit, so Div must never hit the gcd panic
+        #[test]
+        fn division_by_nonzero_never_panics_over_primes() {
+            assert_division_inverts_multiplication::<2>();
+            assert_division_inverts_multiplication::<13>();
+            assert_division_inverts_multiplication::<97>();
+            assert_division_inverts_multiplication::<251>();
+        }
+    }
+
+    mod field_trait {
+        use super::super::*;
+
+        // a / b^e, written once for any field
+        fn div_pow<F: Field>(a: F, b: F, e: u64) -> Option<F> {
+            Some(a * b.pow(e).inverse()?)
+        }
+
+        #[test]
+        fn generic_function_runs_over_prime_field() {
+            let a = FieldElement::<7>::new(3);
+            let b = FieldElement::<7>::new(2);
+
+            // 2^3 = 1 mod 7
+            assert_eq!(div_pow(a, b, 3).unwrap().val(), 3);
+            // 2^2 = 4, 4^-1 = 2, 3 * 2 = 6
+            assert_eq!(div_pow(a, b, 2).unwrap().val(), 6);
+            assert!(div_pow(a, <FieldElement<7> as Field>::zero(), 1).is_none());
+        }
+
+        #[test]
+        fn trait_constants_match_inherent_ones() {
+            assert_eq!(<FieldElement<7> as Field>::zero().val(), 0);
+            assert_eq!(<FieldElement<7> as Field>::one().val(), 1);
+        }
+    }
+
+    mod binary_field {
+        use super::super::*;
+
+        type F2 = FieldElement<2>;
+
+        #[test]
+        fn addition_is_xor() {
+            for a in 0..2 {
+                for b in 0..2 {
+                    assert_eq!((F2::new(a) + F2::new(b)).val(), a ^ b);
+                    assert_eq!((F2::new(a) - F2::new(b)).val(), a ^ b);
+                }
+            }
+            assert_eq!(-F2::one(), F2::one());
+        }
+
+        #[test]
+        fn multiplication_is_and() {
+            for a in 0..2 {
+                for b in 0..2 {
+                    assert_eq!((F2::new(a) * F2::new(b)).val(), a & b);
+                }
+            }
+        }
+
+        #[test]
+        fn only_one_is_invertible() {
+            assert_eq!(F2::one().inverse(), Some(F2::one()));
+            assert_eq!(F2::one().inverse_pow(), Some(F2::one()));
+            assert_eq!(F2::zero().inverse(), None);
+            assert_eq!(F2::zero().try_inverse(), Err(ZookError::DivisionByZero));
+        }
+
+        #[test]
+        fn everything_is_a_square() {
+            assert_eq!(F2::zero().legendre(), 0);
+            assert_eq!(F2::one().legendre(), 1);
+            assert!(F2::zero().is_square() && F2::one().is_square());
+            assert_eq!(F2::zero().sqrt(), Some(F2::zero()));
+            assert_eq!(F2::one().sqrt(), Some(F2::one()));
+        }
+
+        #[test]
+        fn exponentiation_is_trivial() {
+            assert_eq!(F2::one().pow(12345), F2::one());
+            assert_eq!(F2::zero().pow(0), F2::one());
+            assert_eq!(F2::zero().pow(3), F2::zero());
+        }
+    }
+
+    mod bits {
+        use super::super::*;
+
+        #[test]
+        fn bit_reads_the_canonical_value() {
+            // 0b1011_0010
+            let a = FieldElement::<251>::new(178);
+            let bits: Vec<_> = (0..8).map(|i| a.bit(i)).collect();
+            assert_eq!(bits, [false, true, false, false, true, true, false, true]);
+            // 300 reduces to 49 = 0b11_0001
+            assert!(FieldElement::<251>::new(300).bit(0));
+            assert!(!FieldElement::<251>::new(300).bit(3));
+        }
+
+        #[test]
+        fn bit_is_false_past_the_word() {
+            let a = FieldElement::<4294967291>::new(4294967290);
+            assert!(a.bit(31));
+            assert!(!a.bit(32));
+            assert!(!a.bit(u32::MAX));
+        }
+
+        #[test]
+        fn bit_agrees_with_to_bits_le() {
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                for (i, &b) in a.to_bits_le().iter().enumerate() {
+                    assert_eq!(a.bit(i as u32), b);
+                }
+            }
+        }
+
+        #[test]
+        fn to_bits_le_has_field_bit_length() {
+            assert_eq!(FieldElement::<2>::new(1).to_bits_le().len(), 1);
+            assert_eq!(FieldElement::<7>::new(1).to_bits_le().len(), 3);
+            assert_eq!(FieldElement::<17>::new(1).to_bits_le().len(), 5);
+            assert_eq!(FieldElement::<4294967291>::new(1).to_bits_le().len(), 32);
+        }
+
+        #[test]
+        fn to_bits_le_is_least_significant_first() {
+            // 6 = 0b0110
+            assert_eq!(
+                FieldElement::<17>::new(6).to_bits_le(),
+                vec![false, true, true, false, false]
+            );
+        }
+
+        #[test]
+        fn bits_round_trip() {
+            for a in 0..97 {
+                let a = FieldElement::<97>::new(a);
+                assert_eq!(FieldElement::from_bits_le(&a.to_bits_le()), a);
+            }
+        }
+
+        #[test]
+        fn recomposition_equals_value() {
+            let a = FieldElement::<4294967291>::new(3141592653);
+            let recomposed = a
+                .to_bits_le()
+                .iter()
+                .enumerate()
+                .fold(0u64, |acm, (i, &bit)| acm | (u64::from(bit) << i));
+            assert_eq!(recomposed, a.to_u64());
+        }
+
+        #[test]
+        fn from_bits_le_reduces() {
+            // 0b11111 = 31 = 14 mod 17
+            assert_eq!(FieldElement::<17>::from_bits_le(&[true; 5]).val(), 14);
+            assert_eq!(FieldElement::<17>::from_bits_le(&[]).val(), 0);
+        }
+    }
+
+    mod bytes {
+        use super::super::*;
+
+        #[test]
+        fn from_bytes_wide_reduces_long_digests() {
+            let digest: Vec<u8> = (1..=16).map(|b| b * 15).collect();
+            let expected = u128::from_be_bytes(digest.clone().try_into().unwrap()) % 4294967291;
+
+            let a = FieldElement::<4294967291>::from_bytes_wide(&digest);
+            assert_eq!(u128::from(a.val()), expected);
+            assert_eq!(FieldElement::<4294967291>::from_bytes_wide(&digest), a);
+        }
+
+        #[test]
+        fn from_bytes_wide_is_big_endian() {
+            assert_eq!(FieldElement::<65537>::from_bytes_wide(&[1, 0]).val(), 256);
+            assert_eq!(FieldElement::<97>::from_bytes_wide(&[]).val(), 0);
+            assert_eq!(
+                FieldElement::<97>::from_bytes_wide(&[0, 0, 0, 0, 0, 98]).val(),
+                1
+            );
+        }
+
+        #[test]
+        fn coord_bytes_fits_the_modulus() {
+            assert_eq!(FieldElement::<2>::COORD_BYTES, 1);
+            assert_eq!(FieldElement::<3>::COORD_BYTES, 1);
+            assert_eq!(FieldElement::<251>::COORD_BYTES, 1);
+            assert_eq!(FieldElement::<257>::COORD_BYTES, 2);
+            assert_eq!(FieldElement::<65537>::COORD_BYTES, 3);
+            assert_eq!(FieldElement::<4294967291>::COORD_BYTES, 4);
+        }
+
+        #[test]
+        fn bytes_round_trip() {
+            let a = FieldElement::<257>::new(256);
+            assert_eq!(a.to_bytes(), vec![0, 1]);
+            assert_eq!(FieldElement::from_bytes(&a.to_bytes()), Ok(a));
+            assert_eq!(FieldElement::<17>::new(5).to_bytes(), vec![5]);
+        }
+
+        #[test]
+        fn from_bytes_rejects_bad_length_and_range() {
+            assert_eq!(
+                FieldElement::<257>::from_bytes(&[1]),
+                Err(ZookError::LengthMismatch)
+            );
+            assert_eq!(
+                FieldElement::<257>::from_bytes(&[1, 0, 0]),
+                Err(ZookError::LengthMismatch)
+            );
+            assert_eq!(
+                FieldElement::<257>::from_bytes(&[1, 1]),
+                Err(ZookError::InvalidEncoding)
+            );
+        }
+
+        #[test]
+        fn repr_round_trips() {
+            for v in 0..97 {
+                let a = FieldElement::<97>::new(v);
+                assert_eq!(a.to_repr(), [v as u8, 0, 0, 0]);
+                assert_eq!(FieldElement::from_repr(a.to_repr()), Some(a));
+            }
+            let a = FieldElement::<4294967291>::new(0x01020304);
+            assert_eq!(a.to_repr(), [4, 3, 2, 1]);
+            assert_eq!(FieldElement::from_repr(a.to_repr()), Some(a));
+        }
+
+        #[test]
+        fn from_repr_rejects_non_canonical_encodings() {
+            assert_eq!(FieldElement::<97>::from_repr([97, 0, 0, 0]), None);
+            assert_eq!(FieldElement::<97>::from_repr([1, 0, 0, 1]), None);
+            assert_eq!(
+                FieldElement::<4294967291>::from_repr([0xfb, 0xff, 0xff, 0xff]),
+                None
+            );
+        }
+    }
+
+    mod slices {
+        use super::super::*;
+
+        fn elements(values: &[u32]) -> Vec<FieldElement<7>> {
+            values.iter().map(|&v| FieldElement::new(v)).collect()
+        }
+
+        #[test]
+        fn horner_eval_of_empty_slice_is_zero() {
+            assert_eq!(
+                horner_eval::<7>(&[], FieldElement::new(3)),
+                FieldElement::zero()
+            );
+        }
+
+        #[test]
+        fn horner_eval_of_constant_ignores_x() {
+            for x in 0..7 {
+                assert_eq!(
+                    horner_eval(&elements(&[5]), FieldElement::new(x)),
+                    FieldElement::new(5)
+                );
+            }
+        }
+
+        #[test]
+        fn horner_eval_matches_hand_computation() {
+            // 1 + 2x + 3x^2 at x = 2 is 17 = 3 mod 7
+            let coeffs = elements(&[1, 2, 3]);
+            assert_eq!(
+                horner_eval(&coeffs, FieldElement::new(2)),
+                FieldElement::new(3)
+            );
+        }
+
+        #[test]
+        fn inner_product_of_empty_slices_is_zero() {
+            assert_eq!(inner_product::<7>(&[], &[]), Ok(FieldElement::zero()));
+        }
+
+        #[test]
+        fn inner_product_matches_hand_computation() {
+            // 1 * 4 + 2 * 5 + 3 * 6 = 32 = 4 mod 7
+            let a = elements(&[1, 2, 3]);
+            let b = elements(&[4, 5, 6]);
+            assert_eq!(inner_product(&a, &b), Ok(FieldElement::new(4)));
+        }
+
+        #[test]
+        fn hadamard_matches_hand_computation() {
+            // [1 * 4, 2 * 5, 3 * 6] = [4, 10, 18] = [4, 3, 4] mod 7
+            let a = elements(&[1, 2, 3]);
+            let b = elements(&[4, 5, 6]);
+            assert_eq!(hadamard(&a, &b), Ok(elements(&[4, 3, 4])));
+            assert_eq!(hadamard::<7>(&[], &[]), Ok(vec![]));
+        }
+
+        #[test]
+        fn hadamard_rejects_length_mismatch() {
+            assert_eq!(
+                hadamard(&elements(&[1]), &elements(&[1, 2])),
+                Err(ZookError::LengthMismatch)
+            );
+        }
+
+        #[test]
+        fn batch_inverse_matches_inverse() {
+            let values = elements(&[1, 2, 3, 4, 5, 6, 3]);
+            let inverses = batch_inverse(&values);
+
+            assert_eq!(inverses.len(), values.len());
+            for (v, inv) in values.into_iter().zip(inverses) {
+                assert_eq!(Some(inv), v.inverse());
+            }
+            assert!(batch_inverse::<7>(&[]).is_empty());
+        }
+
+        #[test]
+        fn inner_product_rejects_length_mismatch() {
+            assert_eq!(
+                inner_product(&elements(&[1, 2]), &elements(&[1])),
+                Err(ZookError::LengthMismatch)
+            );
+        }
+    }
+
+    #[test]
+    fn field_operations_complete_within_loose_bound() {
+        let start = std::time::Instant::now();
+        let a = FieldElement::<4294967291>::new(123456789);
+        for i in 1..10_000 {
+            let b = FieldElement::new(i);
+            let _c = (a * b).pow(i) + b.inverse().unwrap();
+        }
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+
     #[test]
     #[should_panic(expected = "division by 0")]
     fn it_panics_on_division_by_zero() {
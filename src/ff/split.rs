@@ -0,0 +1,157 @@
+use std::num::NonZeroU32;
+use std::ops::{Add, Mul};
+
+use super::{modulus_add, modulus_mul, multiplicative_inverse, prime_factors, FieldElement};
+
+// experimental residue number system form: an element mod P stored as its residues mod the
+// coprime prime-power factors of P, so add and mul work componentwise without reducing mod P
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitFieldElement<const P: u32> {
+    parts: Vec<(NonZeroU32, u32)>,
+}
+
+impl<const P: u32> SplitFieldElement<P> {
+    pub fn moduli() -> Vec<u32> {
+        coprime_factors(P)
+    }
+
+    pub fn residues(&self) -> Vec<u32> {
+        self.parts.iter().map(|&(_, r)| r).collect()
+    }
+
+    // the plain FieldElement with the same value
+    pub fn canonical(&self) -> FieldElement<P> {
+        self.clone().into()
+    }
+
+    fn zip_with(self, rhs: Self, op: fn(u32, u32, NonZeroU32) -> u32) -> Self {
+        Self {
+            parts: self
+                .parts
+                .into_iter()
+                .zip(rhs.parts)
+                .map(|((m, a), (_, b))| (m, op(a, b, m)))
+                .collect(),
+        }
+    }
+}
+
+impl<const P: u32> From<FieldElement<P>> for SplitFieldElement<P> {
+    fn from(a: FieldElement<P>) -> Self {
+        Self {
+            parts: coprime_factors(P)
+                .into_iter()
+                .map(|m| (NonZeroU32::new(m).unwrap(), a.val() % m))
+                .collect(),
+        }
+    }
+}
+
+// Chinese remainder theorem recombination
+impl<const P: u32> From<SplitFieldElement<P>> for FieldElement<P> {
+    fn from(a: SplitFieldElement<P>) -> Self {
+        let n = NonZeroU32::new(P).unwrap();
+        let val = a.parts.iter().fold(0, |acm, &(m, r)| {
+            let cofactor = P / m.get();
+            let cofactor_inv = match NonZeroU32::new(cofactor % m.get()) {
+                Some(c) => multiplicative_inverse(c, m).expect("moduli are not coprime"),
+                // m == 1 only when P == 1
+                None => NonZeroU32::MIN,
+            };
+            let term = modulus_mul(modulus_mul(r, cofactor_inv.get(), n), cofactor, n);
+            modulus_add(acm, term, n)
+        });
+        FieldElement::new(val)
+    }
+}
+
+impl<const P: u32> Add for SplitFieldElement<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, modulus_add)
+    }
+}
+
+impl<const P: u32> Mul for SplitFieldElement<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, modulus_mul)
+    }
+}
+
+// n split into its maximal prime-power divisors
+fn coprime_factors(n: u32) -> Vec<u32> {
+    let factors: Vec<_> = prime_factors(n)
+        .into_iter()
+        .map(|q| {
+            let mut power = q;
+            while (n / power).is_multiple_of(q) {
+                power *= q;
+            }
+            power
+        })
+        .collect();
+    if factors.is_empty() {
+        vec![n]
+    } else {
+        factors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::eq_canonical;
+    use super::*;
+
+    // 2^3 * 3^2 * 5
+    const P: u32 = 360;
+
+    #[test]
+    fn it_splits_into_prime_powers() {
+        assert_eq!(SplitFieldElement::<P>::moduli(), vec![8, 9, 5]);
+        assert_eq!(SplitFieldElement::<97>::moduli(), vec![97]);
+        assert_eq!(coprime_factors(4294967291), vec![4294967291]);
+        assert_eq!(coprime_factors(1 << 31), vec![1 << 31]);
+        assert_eq!(coprime_factors(3 * 3 * 65521), vec![9, 65521]);
+        assert_eq!(coprime_factors(1), vec![1]);
+    }
+
+    #[test]
+    fn it_round_trips() {
+        for a in 0..P {
+            let a = FieldElement::<P>::new(a);
+            assert_eq!(FieldElement::from(SplitFieldElement::from(a)), a);
+        }
+    }
+
+    #[test]
+    fn it_stores_residues() {
+        let a = SplitFieldElement::from(FieldElement::<P>::new(100));
+        assert_eq!(a.residues(), vec![4, 1, 0]);
+    }
+
+    #[test]
+    fn canonical_compares_across_forms() {
+        let a = FieldElement::<P>::new(100);
+        let x = SplitFieldElement::from(a);
+        assert_eq!(x.canonical(), a);
+        assert!(eq_canonical(x.clone(), a));
+        assert!(eq_canonical(a, x.clone()));
+        assert!(!eq_canonical(x, FieldElement::<P>::new(101)));
+    }
+
+    #[test]
+    fn arithmetic_matches_direct_arithmetic() {
+        for a in (0..P).step_by(7) {
+            for b in (0..P).step_by(11) {
+                let (a, b) = (FieldElement::<P>::new(a), FieldElement::<P>::new(b));
+                let (x, y) = (SplitFieldElement::from(a), SplitFieldElement::from(b));
+
+                assert_eq!(FieldElement::from(x.clone() + y.clone()), a + b);
+                assert_eq!(FieldElement::from(x * y), a * b);
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::FieldElement;
+
+// one entry per element of F_P, indexed by canonical value, so only sensible for small P
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldTable<const P: u32, T> {
+    data: Vec<T>,
+}
+
+impl<const P: u32, T> FieldTable<P, T> {
+    pub fn new(f: impl Fn(FieldElement<P>) -> T) -> Self {
+        FieldTable {
+            data: (0..P).map(|x| f(FieldElement::new(x))).collect(),
+        }
+    }
+
+    pub fn get(&self, x: FieldElement<P>) -> &T {
+        &self.data[x.to_usize()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_table_matches_inverse() {
+        let table = FieldTable::<97, _>::new(FieldElement::inverse);
+        for a in 0..97 {
+            let a = FieldElement::new(a);
+            assert_eq!(*table.get(a), a.inverse());
+        }
+    }
+
+    #[test]
+    fn sbox_table_matches_pow() {
+        let table = FieldTable::<17, _>::new(FieldElement::pow5);
+        for a in 0..17 {
+            let a = FieldElement::new(a);
+            assert_eq!(*table.get(a), a.pow(5));
+        }
+    }
+}
@@ -1,5 +1,11 @@
 pub mod ec;
+pub mod error;
 pub mod ff;
+pub mod linalg;
+pub mod poly;
+pub mod poseidon;
+
+pub use error::ZookError;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
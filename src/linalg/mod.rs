@@ -0,0 +1,366 @@
+use std::ops::{Index, IndexMut, Mul};
+
+use crate::ff::FieldElement;
+
+// row-major, assumes P is prime so every nonzero pivot is invertible
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix<const P: u32> {
+    rows: usize,
+    cols: usize,
+    data: Vec<FieldElement<P>>,
+}
+
+impl<const P: u32> Matrix<P> {
+    pub fn new(rows: usize, cols: usize, data: Vec<FieldElement<P>>) -> Matrix<P> {
+        if data.len() != rows * cols {
+            panic!("matrix data does not match its dimensions")
+        }
+        Matrix { rows, cols, data }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<FieldElement<P>>>) -> Matrix<P> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            panic!("matrix rows must have equal length")
+        }
+        Matrix::new(rows.len(), cols, rows.into_iter().flatten().collect())
+    }
+
+    pub fn zero(rows: usize, cols: usize) -> Matrix<P> {
+        Matrix::new(rows, cols, vec![FieldElement::zero(); rows * cols])
+    }
+
+    pub fn identity(n: usize) -> Matrix<P> {
+        let mut m = Matrix::zero(n, n);
+        for i in 0..n {
+            m[(i, i)] = FieldElement::one();
+        }
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn transpose(&self) -> Matrix<P> {
+        let mut t = Matrix::zero(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t[(j, i)] = self[(i, j)];
+            }
+        }
+        t
+    }
+
+    pub fn mul_vec(&self, v: &[FieldElement<P>]) -> Vec<FieldElement<P>> {
+        if v.len() != self.cols {
+            panic!("vector length does not match the number of columns")
+        }
+        (0..self.rows)
+            .map(|i| (0..self.cols).fold(FieldElement::zero(), |acm, j| acm + self[(i, j)] * v[j]))
+            .collect()
+    }
+
+    // Gauss-Jordan elimination
+    pub fn reduced_row_echelon(&self) -> Matrix<P> {
+        let mut m = self.clone();
+        m.reduce();
+        m
+    }
+
+    pub fn rank(&self) -> usize {
+        let mut m = self.clone();
+        m.reduce().0.len()
+    }
+
+    // a particular solution of self * x = b with free variables set to zero, None if inconsistent
+    pub fn solve(&self, b: &[FieldElement<P>]) -> Option<Vec<FieldElement<P>>> {
+        if b.len() != self.rows {
+            panic!("right-hand side does not match the number of rows")
+        }
+
+        let mut augmented = Matrix::zero(self.rows, self.cols + 1);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                augmented[(i, j)] = self[(i, j)];
+            }
+            augmented[(i, self.cols)] = b[i];
+        }
+
+        let (pivots, _) = augmented.reduce();
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+
+        let mut x = vec![FieldElement::zero(); self.cols];
+        for (row, &col) in pivots.iter().enumerate() {
+            x[col] = augmented[(row, self.cols)];
+        }
+        Some(x)
+    }
+
+    pub fn determinant(&self) -> FieldElement<P> {
+        if self.rows != self.cols {
+            panic!("determinant of a non-square matrix")
+        }
+
+        let mut m = self.clone();
+        let (pivots, det) = m.reduce();
+        if pivots.len() == self.rows {
+            det
+        } else {
+            FieldElement::zero()
+        }
+    }
+
+    // None when the matrix is singular
+    pub fn inverse(&self) -> Option<Matrix<P>> {
+        if self.rows != self.cols {
+            panic!("inverse of a non-square matrix")
+        }
+
+        let n = self.rows;
+        if n == 0 {
+            // the empty identity
+            return Some(Matrix::zero(0, 0));
+        }
+
+        let mut augmented = Matrix::zero(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                augmented[(i, j)] = self[(i, j)];
+            }
+            augmented[(i, n + i)] = FieldElement::one();
+        }
+
+        let (pivots, _) = augmented.reduce();
+        if pivots.len() < n || pivots[n - 1] >= n {
+            return None;
+        }
+
+        let mut inv = Matrix::zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                inv[(i, j)] = augmented[(i, n + j)];
+            }
+        }
+        Some(inv)
+    }
+
+    fn row_times_col(&self, rhs: &Self, row: usize, col: usize) -> FieldElement<P> {
+        (0..self.cols).fold(FieldElement::zero(), |acm, k| {
+            acm + self[(row, k)] * rhs[(k, col)]
+        })
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for c in 0..self.cols {
+            self.data.swap(a * self.cols + c, b * self.cols + c);
+        }
+    }
+
+    // reduces in place, returns the pivot columns and the determinant scaling of the reduction
+    fn reduce(&mut self) -> (Vec<usize>, FieldElement<P>) {
+        let mut pivots = Vec::new();
+        let mut det = FieldElement::one();
+        let mut row = 0;
+
+        for col in 0..self.cols {
+            if row == self.rows {
+                break;
+            }
+            let Some(pivot) = (row..self.rows).find(|&r| self[(r, col)].val() != 0) else {
+                continue;
+            };
+
+            if pivot != row {
+                self.swap_rows(pivot, row);
+                det = -det;
+            }
+
+            let pivot_val = self[(row, col)];
+            det *= pivot_val;
+            let pivot_inv = pivot_val.inverse().expect("pivot is not invertible");
+            for c in 0..self.cols {
+                self[(row, c)] *= pivot_inv;
+            }
+
+            for r in 0..self.rows {
+                let factor = self[(r, col)];
+                if r == row || factor.val() == 0 {
+                    continue;
+                }
+                for c in 0..self.cols {
+                    self[(r, c)] = self[(r, c)] - factor * self[(row, c)];
+                }
+            }
+
+            pivots.push(col);
+            row += 1;
+        }
+
+        (pivots, det)
+    }
+}
+
+impl<const P: u32> Index<(usize, usize)> for Matrix<P> {
+    type Output = FieldElement<P>;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<const P: u32> IndexMut<(usize, usize)> for Matrix<P> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<const P: u32> Mul for Matrix<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.cols != rhs.rows {
+            panic!("matrix dimensions do not match for multiplication")
+        }
+
+        let mut product = Matrix::zero(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                product[(i, j)] = self.row_times_col(&rhs, i, j);
+            }
+        }
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&[u32]]) -> Matrix<7> {
+        Matrix::from_rows(
+            rows.iter()
+                .map(|row| row.iter().map(|&v| FieldElement::new(v)).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn identity_is_multiplicative_identity() {
+        let m = matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(m.clone() * Matrix::identity(3), m);
+        assert_eq!(Matrix::identity(2) * m.clone(), m);
+    }
+
+    #[test]
+    fn it_multiplies() {
+        let a = matrix(&[&[1, 2], &[3, 4]]);
+        let b = matrix(&[&[5, 6], &[0, 1]]);
+        // [[5, 8], [15, 22]] mod 7
+        assert_eq!(a * b, matrix(&[&[5, 1], &[1, 1]]));
+    }
+
+    #[test]
+    fn it_multiplies_vectors() {
+        let m = matrix(&[&[1, 2], &[3, 4], &[5, 6]]);
+        let v = vec![FieldElement::new(1), FieldElement::new(2)];
+        // [5, 11, 17] mod 7
+        assert_eq!(
+            m.mul_vec(&v),
+            vec![5, 4, 3]
+                .into_iter()
+                .map(FieldElement::new)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_transposes() {
+        let m = matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(m.transpose(), matrix(&[&[1, 4], &[2, 5], &[3, 6]]));
+        assert_eq!(m.transpose().transpose(), m);
+    }
+
+    #[test]
+    fn it_reduces_to_row_echelon_form() {
+        let m = matrix(&[&[0, 2, 4], &[1, 1, 1]]);
+        assert_eq!(m.reduced_row_echelon(), matrix(&[&[1, 0, 6], &[0, 1, 2]]));
+    }
+
+    fn vector(values: &[u32]) -> Vec<FieldElement<7>> {
+        values.iter().map(|&v| FieldElement::new(v)).collect()
+    }
+
+    fn apply(m: &Matrix<7>, x: &[FieldElement<7>]) -> Vec<FieldElement<7>> {
+        let column = Matrix::new(x.len(), 1, x.to_vec());
+        (m.clone() * column).data
+    }
+
+    #[test]
+    fn it_computes_rank() {
+        assert_eq!(matrix(&[&[1, 2], &[3, 4]]).rank(), 2);
+        assert_eq!(matrix(&[&[1, 2, 3], &[2, 4, 6]]).rank(), 1);
+        assert_eq!(Matrix::<7>::zero(3, 3).rank(), 0);
+    }
+
+    #[test]
+    fn it_solves_system_with_unique_solution() {
+        let m = matrix(&[&[2, 1], &[1, 3]]);
+        let b = vector(&[3, 5]);
+        let x = m.solve(&b).unwrap();
+
+        assert_eq!(apply(&m, &x), b);
+    }
+
+    #[test]
+    fn inconsistent_system_has_no_solution() {
+        let m = matrix(&[&[1, 2], &[2, 4]]);
+        assert!(m.solve(&vector(&[1, 3])).is_none());
+    }
+
+    #[test]
+    fn it_finds_particular_solution_of_underdetermined_system() {
+        let m = matrix(&[&[1, 2, 3], &[0, 1, 4]]);
+        let b = vector(&[6, 2]);
+        let x = m.solve(&b).unwrap();
+
+        assert_eq!(x.len(), 3);
+        assert_eq!(apply(&m, &x), b);
+    }
+
+    #[test]
+    fn it_computes_determinant() {
+        // 1 * 4 - 2 * 3 = -2 = 5 mod 7
+        assert_eq!(matrix(&[&[1, 2], &[3, 4]]).determinant().val(), 5);
+        // a row swap flips the sign
+        assert_eq!(matrix(&[&[3, 4], &[1, 2]]).determinant().val(), 2);
+        assert_eq!(matrix(&[&[1, 2], &[2, 4]]).determinant().val(), 0);
+    }
+
+    #[test]
+    fn it_inverts_invertible_matrix() {
+        let m = matrix(&[&[2, 1, 0], &[0, 3, 1], &[1, 0, 5]]);
+        let inv = m.inverse().unwrap();
+
+        assert_eq!(m.clone() * inv.clone(), Matrix::identity(3));
+        assert_eq!(inv * m, Matrix::identity(3));
+    }
+
+    #[test]
+    fn singular_matrix_has_no_inverse() {
+        assert!(matrix(&[&[1, 2], &[2, 4]]).inverse().is_none());
+        assert!(matrix(&[&[0, 0], &[0, 0]]).inverse().is_none());
+    }
+
+    #[test]
+    fn empty_matrix_is_its_own_inverse() {
+        let empty = Matrix::<7>::zero(0, 0);
+        assert_eq!(empty.inverse(), Some(Matrix::identity(0)));
+    }
+}
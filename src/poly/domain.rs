@@ -0,0 +1,188 @@
+use crate::ff::FieldElement;
+
+// multiplicative subgroup of size `size` generated by a primitive root of unity
+#[derive(Clone, Copy)]
+pub struct EvaluationDomain<const P: u32> {
+    size: usize,
+    generator: FieldElement<P>,
+    offset: FieldElement<P>,
+}
+
+impl<const P: u32> EvaluationDomain<P> {
+    // requires size to be a power of two dividing P - 1
+    pub fn new(size: usize) -> Option<EvaluationDomain<P>> {
+        let n = u32::try_from(size).ok()?;
+        if !n.is_power_of_two() || !(P - 1).is_multiple_of(n) {
+            return None;
+        }
+
+        // c^((P - 1) / n) has order dividing n, and exactly n unless its n/2-th power is 1
+        let generator = (1..P)
+            .filter_map(|c| FieldElement::<P>::new(c).pow_cofactor(n))
+            .find(|w| n == 1 || w.pow(n / 2).val() != 1)?;
+
+        Some(EvaluationDomain {
+            size,
+            generator,
+            offset: FieldElement::one(),
+        })
+    }
+
+    // the coset offset * <w>, offset must be nonzero and outside the subgroup. only
+    // coset_elements and coset_vanishing_eval use the offset, elements and the Polynomial
+    // domain methods still work over the subgroup itself
+    pub fn new_coset(size: usize, offset: FieldElement<P>) -> Option<EvaluationDomain<P>> {
+        let domain = Self::new(size)?;
+        if offset.val() == 0 || offset.pow(size as u32).val() == 1 {
+            return None;
+        }
+        Some(EvaluationDomain { offset, ..domain })
+    }
+
+    pub fn offset(&self) -> FieldElement<P> {
+        self.offset
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn generator(&self) -> FieldElement<P> {
+        self.generator
+    }
+
+    // the subgroup, regardless of any coset offset
+    pub fn elements(&self) -> Vec<FieldElement<P>> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut cur = FieldElement::one();
+        for _ in 0..self.size {
+            elements.push(cur);
+            cur *= self.generator;
+        }
+        elements
+    }
+
+    pub fn coset_elements(&self) -> Vec<FieldElement<P>> {
+        self.elements()
+            .into_iter()
+            .map(|w| self.offset * w)
+            .collect()
+    }
+
+    // x^n - offset^n, which vanishes exactly on the coset
+    pub fn coset_vanishing_eval(&self, x: FieldElement<P>) -> FieldElement<P> {
+        let n = self.size as u32;
+        x.pow(n) - self.offset.pow(n)
+    }
+}
+
+// in-place radix-2 transform, values[i] becomes sum_j values[j] * omega^(i * j)
+pub(crate) fn ntt<const P: u32>(values: &mut [FieldElement<P>], omega: FieldElement<P>) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = omega.pow((n / len) as u32);
+        for chunk in values.chunks_mut(len) {
+            let mut w = FieldElement::one();
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = *b * w;
+                *b = *a - t;
+                *a += t;
+                w *= step;
+            }
+        }
+        len *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_sizes_not_dividing_p_minus_one() {
+        assert!(EvaluationDomain::<17>::new(32).is_none());
+        assert!(EvaluationDomain::<17>::new(3).is_none());
+        assert!(EvaluationDomain::<17>::new(0).is_none());
+    }
+
+    #[test]
+    fn generator_has_exact_order() {
+        let domain = EvaluationDomain::<17>::new(8).unwrap();
+        let w = domain.generator();
+
+        assert_eq!(w.pow(8).val(), 1);
+        assert_ne!(w.pow(4).val(), 1);
+    }
+
+    #[test]
+    fn elements_are_distinct_powers() {
+        let elements = EvaluationDomain::<97>::new(16).unwrap().elements();
+
+        for (i, a) in elements.iter().enumerate() {
+            for b in &elements[i + 1..] {
+                assert_ne!(a.val(), b.val());
+            }
+        }
+    }
+
+    #[test]
+    fn new_coset_rejects_offsets_inside_the_subgroup() {
+        assert!(EvaluationDomain::<17>::new_coset(4, FieldElement::zero()).is_none());
+        assert!(EvaluationDomain::<17>::new_coset(4, FieldElement::new(4)).is_none());
+        assert!(EvaluationDomain::<17>::new_coset(4, FieldElement::new(3)).is_some());
+    }
+
+    #[test]
+    fn coset_vanishing_is_zero_exactly_on_the_coset() {
+        let domain = EvaluationDomain::<17>::new_coset(4, FieldElement::new(3)).unwrap();
+        let coset = domain.coset_elements();
+        assert_eq!(coset.len(), 4);
+
+        for x in 0..17 {
+            let x = FieldElement::<17>::new(x);
+            let vanishes = domain.coset_vanishing_eval(x).val() == 0;
+            assert_eq!(vanishes, coset.contains(&x));
+        }
+    }
+
+    #[test]
+    fn plain_domain_is_its_own_coset() {
+        let domain = EvaluationDomain::<17>::new(8).unwrap();
+        assert_eq!(domain.offset(), FieldElement::one());
+        assert_eq!(domain.coset_elements(), domain.elements());
+    }
+
+    #[test]
+    fn ntt_matches_naive_transform() {
+        let domain = EvaluationDomain::<97>::new(8).unwrap();
+        let w = domain.generator();
+        let input: Vec<_> = (1..=8).map(FieldElement::<97>::new).collect();
+
+        let mut output = input.clone();
+        ntt(&mut output, w);
+
+        for (i, out) in output.iter().enumerate() {
+            let expected = input
+                .iter()
+                .enumerate()
+                .fold(FieldElement::zero(), |acm, (j, &v)| {
+                    acm + v * w.pow((i * j) as u32)
+                });
+            assert_eq!(out.val(), expected.val());
+        }
+    }
+}
@@ -0,0 +1,590 @@
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use crate::ff::{self, FieldElement};
+
+mod domain;
+pub mod rs;
+
+pub use domain::EvaluationDomain;
+
+// coefficients are stored little-endian, without trailing zeros
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial<const P: u32> {
+    coeffs: Vec<FieldElement<P>>,
+}
+
+impl<const P: u32> Polynomial<P> {
+    pub fn new(mut coeffs: Vec<FieldElement<P>>) -> Polynomial<P> {
+        while coeffs.last().is_some_and(|c| c.val() == 0) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    pub fn zero() -> Polynomial<P> {
+        Polynomial { coeffs: Vec::new() }
+    }
+
+    pub fn coeffs(&self) -> &[FieldElement<P>] {
+        &self.coeffs
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn eval(&self, x: FieldElement<P>) -> FieldElement<P> {
+        ff::horner_eval(&self.coeffs, x)
+    }
+
+    pub fn leading_coeff(&self) -> Option<FieldElement<P>> {
+        self.coeffs.last().copied()
+    }
+
+    // scaled to leading coefficient 1, zero stays zero
+    pub fn monic(&self) -> Polynomial<P> {
+        match self.leading_coeff() {
+            None => Polynomial::zero(),
+            Some(lead) => self.scale(
+                lead.inverse()
+                    .expect("leading coefficient is not invertible"),
+            ),
+        }
+    }
+
+    pub fn scale(&self, k: FieldElement<P>) -> Polynomial<P> {
+        Polynomial::new(self.coeffs.iter().map(|&c| c * k).collect())
+    }
+
+    // formal derivative, sum of i * a_i * x^(i - 1)
+    pub fn derivative(&self) -> Polynomial<P> {
+        Polynomial::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| FieldElement::new((i % P as usize) as u32) * c)
+                .collect(),
+        )
+    }
+
+    // distinct roots in ascending order, found by evaluating at every element so only
+    // practical for small P
+    pub fn roots(&self) -> Vec<FieldElement<P>> {
+        if self.degree().is_none() {
+            panic!("every element is a root of the zero polynomial")
+        }
+        (0..P)
+            .map(FieldElement::new)
+            .filter(|&x| self.eval(x).val() == 0)
+            .collect()
+    }
+
+    // (quotient, remainder) with deg(remainder) < deg(divisor)
+    pub fn div_rem(&self, divisor: &Self) -> (Polynomial<P>, Polynomial<P>) {
+        let divisor_degree = divisor.degree().expect("division by the zero polynomial");
+        let lead_inv = divisor
+            .leading_coeff()
+            .unwrap()
+            .inverse()
+            .expect("leading coefficient is not invertible");
+
+        let mut rem = self.coeffs.clone();
+        let mut quotient = vec![FieldElement::zero(); rem.len().saturating_sub(divisor_degree)];
+
+        for i in (divisor_degree..rem.len()).rev() {
+            let factor = rem[i] * lead_inv;
+            let shift = i - divisor_degree;
+            quotient[shift] = factor;
+            for (j, &d) in divisor.coeffs.iter().enumerate() {
+                rem[shift + j] -= factor * d;
+            }
+        }
+
+        (Polynomial::new(quotient), Polynomial::new(rem))
+    }
+
+    // monic gcd by Euclid's algorithm, gcd(0, 0) is 0
+    pub fn gcd(&self, other: &Self) -> Polynomial<P> {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while b.degree().is_some() {
+            let (_, rem) = a.div_rem(&b);
+            a = b;
+            b = rem;
+        }
+        a.monic()
+    }
+
+    // no repeated factors, i.e. coprime to its derivative
+    pub fn is_squarefree(&self) -> bool {
+        self.gcd(&self.derivative()).degree() == Some(0)
+    }
+
+    fn mul_poly(&self, rhs: &Self) -> Polynomial<P> {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return Polynomial::zero();
+        }
+
+        let mut coeffs = vec![FieldElement::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] += a * b;
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+
+    pub fn interpolate_over_domain(
+        evals: &[FieldElement<P>],
+        domain: &EvaluationDomain<P>,
+    ) -> Polynomial<P> {
+        if evals.len() != domain.size() {
+            panic!("number of evaluations must match the domain size")
+        }
+
+        let mut coeffs = evals.to_vec();
+        domain::ntt(&mut coeffs, domain.generator().inverse().unwrap());
+
+        let size_inv = FieldElement::new(domain.size() as u32).inverse().unwrap();
+        for c in coeffs.iter_mut() {
+            *c *= size_inv;
+        }
+
+        Polynomial::new(coeffs)
+    }
+
+    // evaluations at the subgroup elements, a coset domain's offset is ignored here and in
+    // interpolate_over_domain, use evaluate_over_coset(domain, domain.offset()) for the coset
+    pub fn evaluate_over_domain(&self, domain: &EvaluationDomain<P>) -> Vec<FieldElement<P>> {
+        if self.coeffs.len() > domain.size() {
+            return domain
+                .elements()
+                .into_iter()
+                .map(|x| self.eval(x))
+                .collect();
+        }
+
+        let mut values = self.coeffs.clone();
+        values.resize(domain.size(), FieldElement::zero());
+        domain::ntt(&mut values, domain.generator());
+        values
+    }
+
+    // evaluations at shift * w^i, p(shift * x) has coefficients a_i * shift^i
+    pub fn evaluate_over_coset(
+        &self,
+        domain: &EvaluationDomain<P>,
+        shift: FieldElement<P>,
+    ) -> Vec<FieldElement<P>> {
+        if self.coeffs.len() > domain.size() {
+            return domain
+                .elements()
+                .into_iter()
+                .map(|x| self.eval(shift * x))
+                .collect();
+        }
+
+        let mut values: Vec<_> = self
+            .coeffs
+            .iter()
+            .zip(shift.powers(self.coeffs.len()))
+            .map(|(&c, s)| c * s)
+            .collect();
+        values.resize(domain.size(), FieldElement::zero());
+        domain::ntt(&mut values, domain.generator());
+        values
+    }
+}
+
+// prod (x - a_i) without building the vanishing polynomial
+pub fn vanishing_eval<const P: u32>(
+    points: &[FieldElement<P>],
+    x: FieldElement<P>,
+) -> FieldElement<P> {
+    points
+        .iter()
+        .fold(FieldElement::one(), |acm, &a| acm * (x - a))
+}
+
+impl<const P: u32> Add for Polynomial<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        let zero = FieldElement::zero();
+        Polynomial::new(
+            (0..len)
+                .map(|i| *self.coeffs.get(i).unwrap_or(&zero) + *rhs.coeffs.get(i).unwrap_or(&zero))
+                .collect(),
+        )
+    }
+}
+
+impl<const P: u32> Sub for Polynomial<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs.scale(-FieldElement::one())
+    }
+}
+
+impl<const P: u32> Mul for Polynomial<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_poly(&rhs)
+    }
+}
+
+impl<const P: u32> fmt::Display for Polynomial<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.coeffs.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (i, c) in self.coeffs.iter().enumerate().rev() {
+            if c.val() == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+
+            if c.val() != 1 || i == 0 {
+                write!(f, "{}", c.val())?;
+            }
+            match i {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", i)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(coeffs: &[u32]) -> Polynomial<7> {
+        Polynomial::new(coeffs.iter().map(|&c| FieldElement::new(c)).collect())
+    }
+
+    #[test]
+    fn it_trims_trailing_zeros() {
+        assert_eq!(poly(&[1, 2, 0, 7]).degree(), Some(1));
+        assert_eq!(poly(&[0, 0]).degree(), None);
+    }
+
+    #[test]
+    fn it_evaluates_with_horner() {
+        // 3x^2 + 2x + 1 at x = 2 is 17 = 3 mod 7
+        assert_eq!(poly(&[1, 2, 3]).eval(FieldElement::new(2)).val(), 3);
+    }
+
+    mod arithmetic {
+        use super::*;
+
+        #[test]
+        fn it_adds_and_subtracts() {
+            assert_eq!(poly(&[1, 2, 3]) + poly(&[6, 5]), poly(&[0, 0, 3]));
+            assert_eq!(poly(&[1, 2, 3]) - poly(&[1, 2, 3]), Polynomial::zero());
+        }
+
+        #[test]
+        fn it_multiplies() {
+            // (x + 1)(x + 6) = x^2 + 7x + 6 = x^2 + 6 mod 7
+            assert_eq!(poly(&[1, 1]) * poly(&[6, 1]), poly(&[6, 0, 1]));
+            assert_eq!(poly(&[1, 1]) * Polynomial::zero(), Polynomial::zero());
+        }
+
+        #[test]
+        fn div_rem_reconstructs_dividend() {
+            let a = poly(&[3, 1, 4, 1, 5]);
+            let b = poly(&[2, 0, 6]);
+            let (q, r) = a.div_rem(&b);
+
+            assert!(r.degree() < b.degree());
+            assert_eq!(q * b + r, a);
+        }
+
+        #[test]
+        fn div_rem_by_larger_degree_is_all_remainder() {
+            let (q, r) = poly(&[1, 2]).div_rem(&poly(&[1, 2, 3]));
+            assert_eq!(q, Polynomial::zero());
+            assert_eq!(r, poly(&[1, 2]));
+        }
+    }
+
+    mod gcd {
+        use super::*;
+
+        #[test]
+        fn gcd_extracts_common_factor() {
+            // f = (x + 1)(x + 4), g = x + 2, h = x + 3
+            let f = poly(&[1, 1]) * poly(&[4, 1]);
+            let g = poly(&[2, 1]);
+            let h = poly(&[3, 1]);
+
+            assert_eq!((f.clone() * g).gcd(&(f.clone() * h)), f);
+        }
+
+        #[test]
+        fn gcd_is_monic() {
+            let f = poly(&[2, 4]);
+            assert_eq!(f.gcd(&(f.clone() * poly(&[1, 1]))), poly(&[4, 1]));
+            assert_eq!(f.gcd(&Polynomial::zero()), poly(&[4, 1]));
+        }
+
+        #[test]
+        fn coprime_polynomials_have_unit_gcd() {
+            assert_eq!(poly(&[2, 1]).gcd(&poly(&[3, 1])), poly(&[1]));
+        }
+
+        #[test]
+        fn it_detects_squarefree() {
+            // (x + 1)(x + 2)
+            assert!((poly(&[1, 1]) * poly(&[2, 1])).is_squarefree());
+            // (x + 1)^2 (x + 2)
+            assert!(!(poly(&[1, 1]) * poly(&[1, 1]) * poly(&[2, 1])).is_squarefree());
+        }
+    }
+
+    mod roots {
+        use super::*;
+
+        #[test]
+        fn derivative_of_x_squared_is_2x() {
+            assert_eq!(poly(&[0, 0, 1]).derivative(), poly(&[0, 2]));
+        }
+
+        #[test]
+        fn derivative_vanishes_at_characteristic() {
+            // d/dx x^7 = 7x^6 = 0 mod 7
+            assert_eq!(
+                poly(&[0, 0, 0, 0, 0, 0, 0, 1]).derivative(),
+                Polynomial::zero()
+            );
+            assert_eq!(poly(&[5]).derivative(), Polynomial::zero());
+        }
+
+        #[test]
+        fn roots_of_linear_factors() {
+            // (x - 2)(x - 3)
+            let p = poly(&[5, 1]) * poly(&[4, 1]);
+            assert_eq!(p.roots(), vec![FieldElement::new(2), FieldElement::new(3)]);
+        }
+
+        #[test]
+        fn irreducible_quadratic_has_no_roots() {
+            // x^2 + 1 is irreducible mod 7
+            assert!(poly(&[1, 0, 1]).roots().is_empty());
+        }
+    }
+
+    mod vanishing {
+        use super::*;
+
+        fn points() -> Vec<FieldElement<7>> {
+            [1, 3, 4].into_iter().map(FieldElement::new).collect()
+        }
+
+        #[test]
+        fn it_equals_expanded_polynomial() {
+            let expanded = points().into_iter().fold(poly(&[1]), |acm, a| {
+                acm * Polynomial::new(vec![-a, FieldElement::one()])
+            });
+
+            for x in 0..7 {
+                let x = FieldElement::new(x);
+                assert_eq!(vanishing_eval(&points(), x), expanded.eval(x));
+            }
+        }
+
+        #[test]
+        fn it_vanishes_on_the_set() {
+            for a in points() {
+                assert_eq!(vanishing_eval(&points(), a), FieldElement::zero());
+            }
+            assert_ne!(
+                vanishing_eval(&points(), FieldElement::new(2)),
+                FieldElement::zero()
+            );
+        }
+
+        #[test]
+        fn empty_set_gives_one() {
+            assert_eq!(
+                vanishing_eval::<7>(&[], FieldElement::new(5)),
+                FieldElement::one()
+            );
+        }
+    }
+
+    mod domain_evaluation {
+        use super::*;
+
+        fn assert_matches_eval(p: &Polynomial<7>, domain: &EvaluationDomain<7>) {
+            let values = p.evaluate_over_domain(domain);
+
+            assert_eq!(values.len(), domain.size());
+            for (x, v) in domain.elements().into_iter().zip(values) {
+                assert_eq!(v.val(), p.eval(x).val());
+            }
+        }
+
+        #[test]
+        fn it_matches_pointwise_eval() {
+            let domain = EvaluationDomain::<7>::new(2).unwrap();
+            assert_matches_eval(&poly(&[3, 5]), &domain);
+            assert_matches_eval(&poly(&[4]), &domain);
+            assert_matches_eval(&Polynomial::zero(), &domain);
+        }
+
+        #[test]
+        fn it_falls_back_for_high_degree() {
+            let domain = EvaluationDomain::<7>::new(2).unwrap();
+            assert_matches_eval(&poly(&[1, 2, 3, 4, 5]), &domain);
+        }
+
+        #[test]
+        fn it_matches_pointwise_eval_on_larger_domain() {
+            let domain = EvaluationDomain::<97>::new(16).unwrap();
+            let p = Polynomial::new(
+                (0..11)
+                    .map(|c| FieldElement::<97>::new(c * c + 3))
+                    .collect(),
+            );
+            let values = p.evaluate_over_domain(&domain);
+
+            for (x, v) in domain.elements().into_iter().zip(values) {
+                assert_eq!(v.val(), p.eval(x).val());
+            }
+        }
+    }
+
+    mod coset_evaluation {
+        use super::*;
+
+        #[test]
+        fn domain_methods_ignore_the_coset_offset() {
+            let p = Polynomial::<97>::new((1..=5).map(FieldElement::new).collect());
+            let plain = EvaluationDomain::<97>::new(8).unwrap();
+            let coset = EvaluationDomain::<97>::new_coset(8, FieldElement::new(5)).unwrap();
+
+            assert_eq!(coset.elements(), plain.elements());
+            assert_eq!(
+                p.evaluate_over_domain(&coset),
+                p.evaluate_over_domain(&plain)
+            );
+
+            let on_coset = p.evaluate_over_coset(&coset, coset.offset());
+            let expected: Vec<_> = coset
+                .coset_elements()
+                .into_iter()
+                .map(|x| p.eval(x))
+                .collect();
+            assert_eq!(on_coset, expected);
+        }
+
+        fn assert_matches_eval(p: &Polynomial<97>, domain: &EvaluationDomain<97>, shift: u32) {
+            let shift = FieldElement::new(shift);
+            let values = p.evaluate_over_coset(domain, shift);
+
+            assert_eq!(values.len(), domain.size());
+            for (x, v) in domain.elements().into_iter().zip(values) {
+                assert_eq!(v, p.eval(shift * x));
+            }
+        }
+
+        #[test]
+        fn it_matches_pointwise_eval_on_shifted_points() {
+            let domain = EvaluationDomain::<97>::new(8).unwrap();
+            let p = Polynomial::new((1..=6).map(|c| FieldElement::<97>::new(c * 11)).collect());
+
+            for shift in [1, 5, 10, 96] {
+                assert_matches_eval(&p, &domain, shift);
+            }
+        }
+
+        #[test]
+        fn it_falls_back_for_high_degree() {
+            let domain = EvaluationDomain::<97>::new(4).unwrap();
+            let p = Polynomial::new((1..=9).map(FieldElement::<97>::new).collect());
+            assert_matches_eval(&p, &domain, 5);
+        }
+    }
+
+    mod domain_interpolation {
+        use super::*;
+
+        #[test]
+        fn it_round_trips_through_evaluation() {
+            let domain = EvaluationDomain::<97>::new(8).unwrap();
+
+            for p in [
+                Polynomial::zero(),
+                Polynomial::new(vec![FieldElement::<97>::new(42)]),
+                Polynomial::new((1..=8).map(FieldElement::<97>::new).collect()),
+                Polynomial::new((0..5).map(|c| FieldElement::<97>::new(7 * c + 1)).collect()),
+            ] {
+                let evals = p.evaluate_over_domain(&domain);
+                assert_eq!(Polynomial::interpolate_over_domain(&evals, &domain), p);
+            }
+        }
+
+        #[test]
+        fn it_recovers_values_at_domain_points() {
+            let domain = EvaluationDomain::<17>::new(4).unwrap();
+            let evals: Vec<_> = [3, 0, 16, 5]
+                .into_iter()
+                .map(FieldElement::<17>::new)
+                .collect();
+            let p = Polynomial::interpolate_over_domain(&evals, &domain);
+
+            for (x, v) in domain.elements().into_iter().zip(evals) {
+                assert_eq!(p.eval(x), v);
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "number of evaluations must match the domain size")]
+        fn it_panics_on_length_mismatch() {
+            let domain = EvaluationDomain::<17>::new(4).unwrap();
+            let _p = Polynomial::interpolate_over_domain(&[FieldElement::new(1)], &domain);
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn zero_polynomial() {
+            assert_eq!(Polynomial::<7>::zero().to_string(), "0");
+        }
+
+        #[test]
+        fn constant() {
+            assert_eq!(poly(&[5]).to_string(), "5");
+            assert_eq!(poly(&[1]).to_string(), "1");
+        }
+
+        #[test]
+        fn multi_term() {
+            assert_eq!(poly(&[1, 2, 3]).to_string(), "3x^2 + 2x + 1");
+        }
+
+        #[test]
+        fn it_skips_zero_and_unit_coefficients() {
+            assert_eq!(poly(&[1, 0, 0, 1]).to_string(), "x^3 + 1");
+            assert_eq!(poly(&[0, 1, 8]).to_string(), "x^2 + x");
+        }
+    }
+}
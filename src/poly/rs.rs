@@ -0,0 +1,132 @@
+// Reed-Solomon code with the message as polynomial coefficients, evaluated at 0, 1, ..., n - 1
+use super::Polynomial;
+use crate::ff::FieldElement;
+
+pub fn encode<const P: u32>(data: &[FieldElement<P>], n: usize) -> Vec<FieldElement<P>> {
+    if n < data.len() || n as u64 > u64::from(P) {
+        panic!("codeword length must be between the message length and P")
+    }
+
+    let message = Polynomial::new(data.to_vec());
+    (0..n as u32)
+        .map(|x| message.eval(FieldElement::new(x)))
+        .collect()
+}
+
+// erasures are None, the message is interpolated from the first k known symbols and
+// None is returned when fewer than k are known or the rest disagree (an error, not an erasure)
+pub fn decode<const P: u32>(
+    received: &[Option<FieldElement<P>>],
+    k: usize,
+) -> Option<Vec<FieldElement<P>>> {
+    // longer codewords would reuse evaluation points
+    if received.len() as u64 > u64::from(P) {
+        return None;
+    }
+
+    let known: Vec<_> = received
+        .iter()
+        .enumerate()
+        .filter_map(|(i, y)| y.map(|y| (FieldElement::new(i as u32), y)))
+        .collect();
+    if known.len() < k {
+        return None;
+    }
+
+    let message = interpolate(&known[..k]);
+    if known[k..].iter().any(|&(x, y)| message.eval(x) != y) {
+        return None;
+    }
+
+    let mut data = message.coeffs().to_vec();
+    data.resize(k, FieldElement::zero());
+    Some(data)
+}
+
+// Lagrange interpolation through points with distinct x
+fn interpolate<const P: u32>(points: &[(FieldElement<P>, FieldElement<P>)]) -> Polynomial<P> {
+    let mut acm = Polynomial::zero();
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        let mut basis = Polynomial::new(vec![FieldElement::one()]);
+        let mut denom = FieldElement::one();
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i != j {
+                basis = basis * Polynomial::new(vec![-xj, FieldElement::one()]);
+                denom *= xi - xj;
+            }
+        }
+        acm = acm + basis.scale(yi / denom);
+    }
+    acm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> Vec<FieldElement<17>> {
+        [3, 0, 11, 5].into_iter().map(FieldElement::new).collect()
+    }
+
+    #[test]
+    fn it_round_trips_without_erasures() {
+        let codeword = encode(&message(), 8);
+        let received: Vec<_> = codeword.into_iter().map(Some).collect();
+        assert_eq!(decode(&received, 4), Some(message()));
+    }
+
+    #[test]
+    fn it_recovers_erasures() {
+        let codeword = encode(&message(), 8);
+        for erased in 0..8 {
+            let mut received: Vec<_> = codeword.iter().copied().map(Some).collect();
+            received[erased] = None;
+            assert_eq!(decode(&received, 4), Some(message()));
+        }
+
+        let mut received: Vec<_> = codeword.into_iter().map(Some).collect();
+        for r in &mut received[..4] {
+            *r = None;
+        }
+        assert_eq!(decode(&received, 4), Some(message()));
+    }
+
+    #[test]
+    fn it_keeps_trailing_zero_coefficients() {
+        let data: Vec<_> = [1, 2, 0, 0]
+            .into_iter()
+            .map(FieldElement::<17>::new)
+            .collect();
+        let received: Vec<_> = encode(&data, 6).into_iter().map(Some).collect();
+        assert_eq!(decode(&received, 4), Some(data));
+    }
+
+    #[test]
+    fn it_rejects_too_many_erasures_and_errors() {
+        let codeword = encode(&message(), 6);
+
+        let mut erased: Vec<_> = codeword.iter().copied().map(Some).collect();
+        erased[0] = None;
+        erased[1] = None;
+        erased[2] = None;
+        assert_eq!(decode(&erased, 4), None);
+
+        let mut corrupted: Vec<_> = codeword.into_iter().map(Some).collect();
+        corrupted[5] = Some(corrupted[5].unwrap() + FieldElement::one());
+        assert_eq!(decode(&corrupted, 4), None);
+    }
+
+    #[test]
+    fn it_rejects_received_words_longer_than_p() {
+        let mut received: Vec<_> = encode(&message(), 17).into_iter().map(Some).collect();
+        received.push(Some(FieldElement::zero()));
+        assert_eq!(decode(&received, 18), None);
+        assert_eq!(decode(&received, 4), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "codeword length must be between the message length and P")]
+    fn it_rejects_codewords_longer_than_p() {
+        let _c = encode(&message(), 18);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::ff::FieldElement;
+use crate::linalg::Matrix;
+
+// x -> x^alpha applied to every state element
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SBox {
+    pub alpha: u32,
+}
+
+impl SBox {
+    pub fn apply<const P: u32>(&self, state: &mut [FieldElement<P>]) {
+        for x in state.iter_mut() {
+            *x = match self.alpha {
+                3 => x.cube(),
+                5 => x.pow5(),
+                7 => x.pow7(),
+                alpha => x.pow(alpha),
+            };
+        }
+    }
+}
+
+pub fn linear_layer<const P: u32>(state: &mut [FieldElement<P>], mds: &Matrix<P>) {
+    let mixed = mds.mul_vec(state);
+    state.copy_from_slice(&mixed);
+}
+
+// rounds of add-round-constants, S-box, then linear layer, all rounds are full
+pub fn permute<const P: u32>(
+    state: &mut [FieldElement<P>],
+    rounds: usize,
+    mds: &Matrix<P>,
+    round_constants: &[Vec<FieldElement<P>>],
+    sbox: SBox,
+) {
+    if round_constants.len() != rounds {
+        panic!("expected one set of round constants per round")
+    }
+    if mds.rows() != state.len() || mds.cols() != state.len() {
+        panic!("MDS matrix does not match the state width")
+    }
+
+    for constants in round_constants {
+        if constants.len() != state.len() {
+            panic!("round constants do not match the state width")
+        }
+        for (x, &c) in state.iter_mut().zip(constants) {
+            *x += c;
+        }
+        sbox.apply(state);
+        linear_layer(state, mds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(values: &[u32]) -> Vec<FieldElement<17>> {
+        values.iter().map(|&v| FieldElement::new(v)).collect()
+    }
+
+    fn mds() -> Matrix<17> {
+        Matrix::from_rows(vec![
+            elements(&[2, 1, 1]),
+            elements(&[1, 2, 1]),
+            elements(&[1, 1, 2]),
+        ])
+    }
+
+    #[test]
+    fn sbox_raises_to_alpha() {
+        let mut state = elements(&[2, 3, 4]);
+        SBox { alpha: 5 }.apply(&mut state);
+        assert_eq!(state, elements(&[32 % 17, 243 % 17, 1024 % 17]));
+
+        let mut state = elements(&[2, 3, 4]);
+        SBox { alpha: 4 }.apply(&mut state);
+        assert_eq!(state, elements(&[16, 81 % 17, 256 % 17]));
+    }
+
+    #[test]
+    fn single_round_matches_hand_computation() {
+        let mut state = elements(&[0, 1, 2]);
+        permute(
+            &mut state,
+            1,
+            &mds(),
+            &[elements(&[1, 2, 2])],
+            SBox { alpha: 3 },
+        );
+
+        // + [1, 2, 2] -> [1, 3, 4], cubed -> [1, 10, 13], times mds -> [25, 34, 37]
+        assert_eq!(state, elements(&[8, 0, 3]));
+    }
+
+    #[test]
+    fn rounds_compose() {
+        let constants = [elements(&[1, 2, 3]), elements(&[4, 5, 6])];
+
+        let mut two_rounds = elements(&[0, 1, 2]);
+        permute(&mut two_rounds, 2, &mds(), &constants, SBox { alpha: 3 });
+
+        let mut one_by_one = elements(&[0, 1, 2]);
+        for c in &constants {
+            permute(
+                &mut one_by_one,
+                1,
+                &mds(),
+                std::slice::from_ref(c),
+                SBox { alpha: 3 },
+            );
+        }
+
+        assert_eq!(two_rounds, one_by_one);
+    }
+
+    #[test]
+    fn zero_rounds_is_identity() {
+        let mut state = elements(&[7, 8, 9]);
+        permute(&mut state, 0, &mds(), &[], SBox { alpha: 5 });
+        assert_eq!(state, elements(&[7, 8, 9]));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected one set of round constants per round")]
+    fn it_panics_on_missing_round_constants() {
+        let mut state = elements(&[0, 1, 2]);
+        permute(
+            &mut state,
+            2,
+            &mds(),
+            &[elements(&[1, 2, 3])],
+            SBox { alpha: 3 },
+        );
+    }
+}